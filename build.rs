@@ -0,0 +1,72 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// `.txt` files directly inside `src/y<year>/<folder>`, e.g. `src/y2022/inputs/05.txt`.
+fn data_files(folder: &str) -> Vec<PathBuf> {
+    let mut files = vec![];
+    let Ok(entries) = fs::read_dir("src") else {
+        return files;
+    };
+
+    for year_dir in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+        let is_year = year_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix('y'))
+            .is_some_and(|year| year.parse::<u16>().is_ok());
+        if !is_year {
+            continue;
+        }
+
+        let dir = year_dir.join(folder);
+        println!("cargo:rerun-if-changed={}", dir.display());
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        files.extend(
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "txt")),
+        );
+    }
+
+    files
+}
+
+/// with the `embed-inputs` feature, writes a table of every input and example to `$OUT_DIR/embedded.rs`.
+/// without it, the table is empty.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+
+    let mut files = vec![];
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        files.extend(data_files("inputs"));
+        files.extend(data_files("examples"));
+    }
+    files.sort();
+
+    let entries: Vec<String> = files
+        .iter()
+        .map(|path| {
+            let key = path.to_string_lossy().replace('\\', "/");
+            format!(
+                "    ({:?}, include_str!({:?})),",
+                key,
+                manifest_dir.join(path).to_string_lossy()
+            )
+        })
+        .collect();
+
+    let table = format!(
+        "/// inputs and examples embedded at compile time, keyed by their path, e.g. `src/y2022/inputs/05.txt`.\npub static EMBEDDED: &[(&str, &str)] = &[\n{}\n];\n",
+        entries.join("\n")
+    );
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("embedded.rs");
+    fs::write(out, table).unwrap();
+}
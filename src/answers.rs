@@ -0,0 +1,212 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{timings, Puzzle, ANSI_ITALIC, ANSI_RESET};
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+/// the answer to a part: a number, a string, or a rendered grid such as the letters drawn by a CRT.
+/// parts may return any `Display` type, `Answer` is there for answers that are not a single line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    pub fn grid<R: Into<String>>(rows: impl IntoIterator<Item = R>) -> Self {
+        Answer::Grid(rows.into_iter().map(Into::into).collect())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = normalize(s);
+        Ok(if s.contains('\n') {
+            Answer::grid(s.lines())
+        } else if let Ok(number) = s.parse() {
+            Answer::Number(number)
+        } else {
+            Answer::Text(s)
+        })
+    }
+}
+
+/// strips trailing newlines, and trailing whitespace of single-line answers.
+/// inner and trailing spaces of grid rows are part of the answer.
+pub fn normalize(answer: &str) -> String {
+    let answer = answer.trim_end_matches(['\r', '\n']);
+    if answer.contains('\n') {
+        answer.to_string()
+    } else {
+        answer.trim_end().to_string()
+    }
+}
+
+/// a single-line version of `answer` for tables, e.g. `<grid 40x6>`.
+pub fn summary(answer: &str) -> String {
+    match answer.parse() {
+        Ok(Answer::Grid(rows)) => format!(
+            "<grid {}x{}>",
+            rows.iter()
+                .map(|row| row.chars().count())
+                .max()
+                .unwrap_or(0),
+            rows.len()
+        ),
+        _ => answer.to_string(),
+    }
+}
+
+/// prints an answer the way `parse_answers` reads it back: single-line answers next to their timing,
+/// grids on lines of their own with the timing below.
+pub fn print_answer(answer: &str, elapsed: Duration) {
+    if answer.contains('\n') {
+        println!("{}", answer);
+        println!("{}(elapsed: {:.2?}){}", ANSI_ITALIC, elapsed, ANSI_RESET);
+    } else {
+        println!(
+            "{} {}(elapsed: {:.2?}){}",
+            answer, ANSI_ITALIC, elapsed, ANSI_RESET
+        );
+    }
+}
+
+pub(crate) fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// one line of `--json` output, e.g. `{"part":1,"answer":"24000","elapsed_ns":1200,"panic":null}`.
+pub fn json_line(
+    part: u8,
+    answer: Option<&str>,
+    elapsed: Option<Duration>,
+    panic: Option<&str>,
+) -> String {
+    let null = || "null".to_string();
+    format!(
+        "{{\"part\":{},\"answer\":{},\"elapsed_ns\":{},\"panic\":{}}}",
+        part,
+        answer.map(json_string).unwrap_or_else(null),
+        elapsed
+            .map(|elapsed| elapsed.as_nanos().to_string())
+            .unwrap_or_else(null),
+        panic.map(json_string).unwrap_or_else(null)
+    )
+}
+
+pub fn answer_path(puzzle: &Puzzle, part: u8) -> PathBuf {
+    puzzle
+        .year_dir()
+        .join("answers")
+        .join(format!("{}_{}.txt", puzzle.padded_day(), part))
+}
+
+pub fn read_answer(puzzle: &Puzzle, part: u8) -> Option<String> {
+    fs::read_to_string(answer_path(puzzle, part))
+        .ok()
+        .map(|answer| normalize(&answer))
+        .filter(|answer| !answer.is_empty())
+}
+
+pub fn write_answer(puzzle: &Puzzle, part: u8, answer: &str) -> io::Result<()> {
+    let path = answer_path(puzzle, part);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, answer)
+}
+
+/// records `answer` if the day binary was started with `--record`, e.g. `cargo solve 2022/05 --record`.
+/// recorded answers belong to the default input, so nothing is recorded for an `--input` file.
+pub fn record_if_requested(bin_name: &str, part: u8, answer: &str) {
+    if !crate::has_flag("--record") {
+        return;
+    }
+    if crate::arg_value("--input").is_some() {
+        eprintln!("not recording answer: it belongs to an --input file, not the default input.");
+        return;
+    }
+
+    let Some(puzzle) = Puzzle::from_bin_name(bin_name) else {
+        eprintln!(
+            "could not record answer: \"{}\" is not a day binary.",
+            bin_name
+        );
+        return;
+    };
+
+    match write_answer(&puzzle, part, answer) {
+        Ok(_) => println!(
+            "Recorded answer to \"{}\"",
+            answer_path(&puzzle, part).display()
+        ),
+        Err(e) => {
+            eprintln!("could not record answer: {}", e);
+            return;
+        }
+    }
+
+    // the first recorded answer of a part marks when it was solved.
+    if let Err(e) = timings::record_solved(&puzzle, part, SystemTime::now()) {
+        eprintln!("could not record solve time: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_from_str() {
+        assert_eq!("24000\n".parse(), Ok(Answer::Number(24000)));
+        assert_eq!("CMZ".parse(), Ok(Answer::Text("CMZ".into())));
+        assert_eq!("#..#\n.##.\n".parse(), Ok(Answer::grid(["#..#", ".##."])));
+        assert_eq!(Answer::grid(["#..#", ".##."]).to_string(), "#..#\n.##.");
+        assert_eq!(summary("#..#\n.##."), "<grid 4x2>");
+        assert_eq!(summary("CMZ"), "CMZ");
+    }
+
+    #[test]
+    fn test_json_line() {
+        assert_eq!(
+            json_line(2, Some("#.\n\"#"), Some(Duration::from_nanos(12)), None),
+            r##"{"part":2,"answer":"#.\n\"#","elapsed_ns":12,"panic":null}"##
+        );
+        assert_eq!(
+            json_line(1, None, None, Some("oops")),
+            r#"{"part":1,"answer":null,"elapsed_ns":null,"panic":"oops"}"#
+        );
+    }
+}
@@ -0,0 +1,155 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::generators;
+use advent_of_code::inputs::named_inputs;
+use advent_of_code::parse_implementations;
+use advent_of_code::runner::{build, Outcome, Runner};
+use advent_of_code::Puzzle;
+use std::fs;
+use std::process;
+use std::time::Duration;
+
+struct Args {
+    puzzle: Puzzle,
+    generated: u64,
+    size: usize,
+    timeout: Option<u64>,
+    release: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let release = args.contains("--release");
+    let generated = args.opt_value_from_str("--generated")?.unwrap_or(10);
+    let size = args.opt_value_from_str("--size")?.unwrap_or(20);
+    let timeout = args.opt_value_from_str("--timeout")?;
+    Ok(Args {
+        puzzle: args.free_from_str()?,
+        generated,
+        size,
+        timeout,
+        release,
+    })
+}
+
+/// the example, every named input and `generated` inputs from the day's generator, as `(name, input)`.
+fn collect_inputs(args: &Args) -> Vec<(String, String)> {
+    let mut inputs = vec![];
+    if let Ok(example) = fs::read_to_string(args.puzzle.data_path("examples")) {
+        inputs.push(("example".to_string(), example));
+    }
+    for input in named_inputs(&args.puzzle) {
+        if let Ok(contents) = fs::read_to_string(&input.path) {
+            inputs.push((input.name, contents));
+        }
+    }
+    for seed in 0..args.generated {
+        match generators::generate(&args.puzzle, args.size, seed) {
+            Some(input) => inputs.push((format!("gen-{}", seed), input)),
+            None => break,
+        }
+    }
+    inputs
+}
+
+fn describe(outcome: &Outcome, part: usize) -> String {
+    match outcome {
+        Outcome::Answers(answers) => answers[part].clone().unwrap_or_else(|| "not solved".into()),
+        Outcome::Panicked(_) => "panicked".into(),
+        Outcome::TimedOut => "timed out".into(),
+    }
+}
+
+fn run(args: &Args) -> Result<bool, String> {
+    let inputs = collect_inputs(args);
+    let Some((_, first_input)) = inputs.first() else {
+        return Err(format!(
+            "no example, named or generated inputs for {}",
+            args.puzzle
+        ));
+    };
+
+    let binary = build(&args.puzzle.bin_name(), args.release)?;
+    let mut runner = Runner::new(args.timeout.map(Duration::from_secs))?;
+
+    let listing = runner
+        .execute(&binary, first_input, &["--list-impls"])?
+        .ok_or("listing implementations timed out")?;
+    let implementations = parse_implementations(&String::from_utf8_lossy(&listing.stdout));
+    if implementations.iter().all(|names| names.len() < 2) {
+        return Err(format!(
+            "{} has no alternative implementations. register them with e.g. `solve!(1, part_one, input, \"naive\" => part_one_naive)`",
+            args.puzzle
+        ));
+    }
+
+    let mut names: Vec<&String> = implementations.iter().flatten().collect();
+    names.sort();
+    names.dedup();
+
+    let mut disagreements = 0;
+    for (input_name, input) in &inputs {
+        let mut outcomes = vec![];
+        for name in &names {
+            outcomes.push((*name, runner.run(&binary, input, &["--impl", name])?));
+        }
+
+        let mut agreed = true;
+        for (part, part_names) in implementations.iter().enumerate() {
+            let answers: Vec<(&String, String)> = outcomes
+                .iter()
+                .filter(|(name, _)| part_names.contains(name))
+                .map(|(name, outcome)| (*name, describe(outcome, part)))
+                .collect();
+            if answers.windows(2).all(|pair| pair[0].1 == pair[1].1) {
+                continue;
+            }
+
+            agreed = false;
+            let answers: Vec<String> = answers
+                .iter()
+                .map(|(name, answer)| format!("{} = {}", name, answer))
+                .collect();
+            println!("✗ {} part {}: {}", input_name, part + 1, answers.join(", "));
+        }
+
+        if agreed {
+            println!("✓ {}", input_name);
+        } else {
+            disagreements += 1;
+        }
+    }
+
+    println!("---");
+    println!(
+        "{} implementations, {} inputs, {} with disagreements.",
+        names.len(),
+        inputs.len(),
+        disagreements
+    );
+    Ok(disagreements == 0)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!(
+                "Need to specify a day (e.g. `2022/6`). example: `cargo difftest 2022/6` ({})",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    match run(&args) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("could not run difftest: {}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -2,22 +2,15 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::Puzzle;
 use std::io::Write;
 use std::path::PathBuf;
 use std::{env::temp_dir, io, process::Command};
 use std::{fs, process};
 
-struct Args {
-    day: u8,
-    year: Option<i16>,
-}
-
-fn parse_args() -> Result<Args, pico_args::Error> {
+fn parse_args() -> Result<Puzzle, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
-    })
+    args.free_from_str()
 }
 
 fn remove_file(path: &PathBuf) {
@@ -39,16 +32,15 @@ fn main() {
     tmp_file_path.push("aoc_input_tmp");
     remove_file(&tmp_file_path);
 
-    let args = match parse_args() {
-        Ok(args) => args,
+    let puzzle = match parse_args() {
+        Ok(puzzle) => puzzle,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
             exit_with_status(1, &tmp_file_path);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
+    let input_path = puzzle.data_path("inputs");
 
     // check if aoc binary exists and is callable.
     if Command::new("aoc").arg("-V").output().is_err() {
@@ -56,20 +48,15 @@ fn main() {
         exit_with_status(1, &tmp_file_path);
     }
 
-    let mut cmd_args = vec![];
-
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec![
+    let cmd_args = vec![
+        "--year".into(),
+        puzzle.year.to_string(),
         "--input-file".into(),
         tmp_file_path.to_string_lossy().to_string(),
         "--day".into(),
-        args.day.to_string(),
+        puzzle.day.to_string(),
         "download".into(),
-    ]);
+    ];
 
     println!("Downloading input with >aoc {}", cmd_args.join(" "));
 
@@ -91,10 +78,18 @@ fn main() {
         }
     }
 
+    if let Err(e) = fs::create_dir_all(input_path.parent().unwrap()) {
+        eprintln!("could not create input directory: {}", e);
+        exit_with_status(1, &tmp_file_path);
+    }
+
     match fs::copy(&tmp_file_path, &input_path) {
         Ok(_) => {
             println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+            println!(
+                "🎄 Successfully wrote input to \"{}\".",
+                input_path.display()
+            );
             exit_with_status(0, &tmp_file_path);
         }
         Err(e) => {
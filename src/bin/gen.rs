@@ -0,0 +1,60 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::generators;
+use advent_of_code::inputs::named_inputs_dir;
+use advent_of_code::Puzzle;
+use std::fs;
+use std::process;
+
+struct Args {
+    puzzle: Puzzle,
+    size: usize,
+    seed: u64,
+    write: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let size = args.opt_value_from_str("--size")?.unwrap_or(100);
+    let seed = args.opt_value_from_str("--seed")?.unwrap_or(0);
+    let write = args.opt_value_from_str("--write")?;
+    Ok(Args {
+        puzzle: args.free_from_str()?,
+        size,
+        seed,
+        write,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!(
+                "Need to specify a day (e.g. `2022/7`). example: `cargo gen 2022/7 --size 500 --seed 1` ({})",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    let Some(input) = generators::generate(&args.puzzle, args.size, args.seed) else {
+        eprintln!("There is no input generator for {}.", args.puzzle);
+        process::exit(1);
+    };
+
+    let Some(name) = args.write else {
+        print!("{}", input);
+        return;
+    };
+
+    let dir = named_inputs_dir(&args.puzzle);
+    let path = dir.join(format!("{}.txt", name));
+    if let Err(e) = fs::create_dir_all(&dir).and_then(|_| fs::write(&path, input)) {
+        eprintln!("Failed to write input to \"{}\": {}", path.display(), e);
+        process::exit(1);
+    }
+    println!("Wrote generated input to \"{}\"", path.display());
+}
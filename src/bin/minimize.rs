@@ -0,0 +1,148 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::minimize::minimize;
+use advent_of_code::runner::{build, Outcome, Runner};
+use advent_of_code::Puzzle;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+struct Args {
+    puzzle: Puzzle,
+    input: Option<PathBuf>,
+    timeout: Option<u64>,
+    against: Option<String>,
+    release: bool,
+    name: String,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let release = args.contains("--release");
+    let input = args.opt_value_from_str("--input")?;
+    let timeout = args.opt_value_from_str("--timeout")?;
+    let against = args.opt_value_from_str("--against")?;
+    let name = args
+        .opt_value_from_str("--name")?
+        .unwrap_or_else(|| "min".into());
+    Ok(Args {
+        puzzle: args.free_from_str()?,
+        input,
+        timeout,
+        against,
+        release,
+        name,
+    })
+}
+
+/// whether `candidate` fails the same way the full input did.
+fn reproduces(
+    runner: &mut Runner,
+    binary: &Path,
+    reference: Option<&Path>,
+    original: &Outcome,
+    candidate: &str,
+) -> Result<bool, String> {
+    let outcome = runner.run(binary, candidate, &[])?;
+    Ok(match (original, reference, outcome) {
+        (Outcome::Answers(_), Some(reference), Outcome::Answers(answers)) => {
+            runner.run(reference, candidate, &[])? != Outcome::Answers(answers)
+        }
+        (Outcome::Answers(_), _, _) => false,
+        (original, _, outcome) => *original == outcome,
+    })
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let input_path = args
+        .input
+        .clone()
+        .unwrap_or_else(|| args.puzzle.data_path("inputs"));
+    let input = fs::read_to_string(&input_path)
+        .map_err(|e| format!("could not read \"{}\": {}", input_path.display(), e))?;
+
+    let binary = build(&args.puzzle.bin_name(), args.release)?;
+    let reference = match &args.against {
+        Some(bin) => Some(build(bin, args.release)?),
+        None => None,
+    };
+
+    let mut runner = Runner::new(args.timeout.map(Duration::from_secs))?;
+
+    // the failure of the full input is what every candidate has to reproduce.
+    let original = runner.run(&binary, &input, &[])?;
+    match (&original, &reference) {
+        (Outcome::TimedOut, _) => println!("Input times out, minimizing..."),
+        (Outcome::Panicked(Some(location)), _) => {
+            println!("Input panics at {}, minimizing...", location)
+        }
+        (Outcome::Panicked(None), _) => println!("Input crashes, minimizing..."),
+        (Outcome::Answers(_), Some(reference)) => {
+            if runner.run(reference, &input, &[])? == original {
+                return Err("both implementations agree on the input".into());
+            }
+            println!("Implementations disagree, minimizing...");
+        }
+        (Outcome::Answers(_), None) => return Err(
+            "the input does not fail. pass `--timeout` or `--against` to look for other failures"
+                .into(),
+        ),
+    }
+
+    let mut error = None;
+    let minimized = minimize(&input, |candidate| {
+        reproduces(
+            &mut runner,
+            &binary,
+            reference.as_deref(),
+            &original,
+            candidate,
+        )
+        .unwrap_or_else(|e| {
+            error.get_or_insert(e);
+            false
+        })
+    });
+    if let Some(e) = error {
+        return Err(e);
+    }
+
+    let out_path = args.puzzle.year_dir().join("examples").join(format!(
+        "{}_{}.txt",
+        args.puzzle.padded_day(),
+        args.name
+    ));
+    fs::write(&out_path, &minimized)
+        .map_err(|e| format!("could not write \"{}\": {}", out_path.display(), e))?;
+
+    println!(
+        "Reduced {} lines to {} in {} runs.",
+        input.lines().count(),
+        minimized.lines().count(),
+        runner.runs
+    );
+    println!("---");
+    println!("🎄 Wrote minimized input to \"{}\".", out_path.display());
+    Ok(())
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!(
+                "Need to specify a day (e.g. `2022/15`). example: `cargo minimize 2022/15 --input big.txt` ({})",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = run(&args) {
+        eprintln!("could not minimize input: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::descriptions::{self, Archived, Fetcher};
+use advent_of_code::Puzzle;
+use std::process;
+
+struct Args {
+    puzzle: Puzzle,
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let force = args.contains(["-f", "--force"]);
+    Ok(Args {
+        puzzle: args.free_from_str()?,
+        force,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!(
+                "Need to specify a day (e.g. `2022/7`). example: `cargo read 2022/7` ({})",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    let fetcher = Fetcher::from_env();
+    let dir = descriptions::puzzles_dir(args.puzzle.year);
+    let path = descriptions::description_path(&dir, &args.puzzle);
+
+    match descriptions::archive(&fetcher, &args.puzzle, &dir, args.force) {
+        Ok(Archived::Created) => println!("Saved description to \"{}\"", path.display()),
+        Ok(Archived::Updated) => println!("Updated description in \"{}\"", path.display()),
+        Ok(Archived::Unchanged) => println!("Description in \"{}\" is up to date", path.display()),
+        Err(e) => {
+            eprintln!("could not archive description: {}", e);
+            process::exit(1);
+        }
+    }
+
+    match descriptions::write_index(&dir, args.puzzle.year) {
+        Ok(index) => {
+            println!("---");
+            println!("🎄 Updated puzzle index \"{}\".", index.display());
+        }
+        Err(e) => {
+            eprintln!("could not write puzzle index: {}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -2,10 +2,14 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::timings::{record_start, timing_path};
+use advent_of_code::Puzzle;
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
+    time::SystemTime,
 };
 
 const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
@@ -17,7 +21,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
+    let input = &advent_of_code::read_file("inputs", YEAR, DAY);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -28,45 +32,72 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_one(&input), None);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_two(&input), None);
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+const BIN_TEMPLATE: &str = r###"
+[[bin]]
+name = "NAME"
+path = "PATH"
+"###;
+
+fn parse_args() -> Result<Puzzle, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     args.free_from_str()
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    fs::create_dir_all(path.parent().unwrap())?;
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+fn register_bin(puzzle: &Puzzle, module_path: &Path) -> Result<(), std::io::Error> {
+    let bin = BIN_TEMPLATE
+        .replace("NAME", &puzzle.bin_name())
+        .replace("PATH", &module_path.to_string_lossy().replace('\\', "/"));
+    OpenOptions::new()
+        .append(true)
+        .open("Cargo.toml")?
+        .write_all(bin.as_bytes())
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+    let puzzle = match parse_args() {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!(
+                "Need to specify a day (e.g. `2022/7`). example: `cargo scaffold 2022/7` ({})",
+                e
+            );
             process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", day);
+    let input_path = puzzle.data_path("inputs");
+    let example_path = puzzle.data_path("examples");
+    let module_path = puzzle.module_path();
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    if let Err(e) = fs::create_dir_all(puzzle.year_dir()) {
+        eprintln!("Failed to create year directory: {}", e);
+        process::exit(1);
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,9 +107,13 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    let module = MODULE_TEMPLATE
+        .replace("YEAR", &puzzle.year.to_string())
+        .replace("DAY", &puzzle.day.to_string());
+
+    match file.write_all(module.as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {}", e);
@@ -86,9 +121,19 @@ fn main() {
         }
     }
 
+    match register_bin(&puzzle, &module_path) {
+        Ok(_) => {
+            println!("Registered binary \"{}\" in Cargo.toml", puzzle.bin_name());
+        }
+        Err(e) => {
+            eprintln!("Failed to register binary in Cargo.toml: {}", e);
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {}", e);
@@ -98,7 +143,7 @@ fn main() {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {}", e);
@@ -106,9 +151,19 @@ fn main() {
         }
     }
 
+    match record_start(&puzzle, SystemTime::now()) {
+        Ok(_) => {
+            println!(
+                "Started the clock in \"{}\"",
+                timing_path(&puzzle).display()
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to record start time: {}", e);
+            process::exit(1);
+        }
+    }
+
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    println!("🎄 Type `cargo solve {}` to run your solution.", puzzle);
 }
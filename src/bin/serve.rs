@@ -0,0 +1,97 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::puzzle::years;
+use advent_of_code::runner::{binary_path, build_all, Runner};
+use advent_of_code::serve::{serve, Solver};
+use advent_of_code::Puzzle;
+use std::net::TcpListener;
+use std::process;
+use std::sync::Arc;
+use std::time::Duration;
+
+struct Args {
+    port: u16,
+    timeout: Option<u64>,
+    release: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        release: args.contains("--release"),
+        port: args.opt_value_from_str("--port")?.unwrap_or(3000),
+        timeout: args.opt_value_from_str("--timeout")?,
+    })
+}
+
+/// every day that has a solution.
+fn solved_puzzles() -> Vec<Puzzle> {
+    years()
+        .into_iter()
+        .flat_map(|year| (1..=25).filter_map(move |day| Puzzle::new(year, day)))
+        .filter(|puzzle| puzzle.module_path().exists())
+        .collect()
+}
+
+/// runs the compiled day binary with `--json --part <p>` and returns the line it printed for the part.
+fn solve_with_binary(
+    puzzle: &Puzzle,
+    part: u8,
+    input: &str,
+    args: &Args,
+) -> Result<Option<String>, String> {
+    if !puzzle.module_path().exists() {
+        return Ok(None);
+    }
+
+    let binary = binary_path(&puzzle.bin_name(), args.release);
+    let mut runner = Runner::new(args.timeout.map(Duration::from_secs))?;
+    let part = part.to_string();
+    let Some(output) = runner.execute(&binary, input, &["--json", "--part", &part])? else {
+        return Err(format!("{} timed out", puzzle));
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match stdout.lines().find(|line| line.starts_with("{\"part\":")) {
+        Some(line) => Ok(Some(line.to_string())),
+        None => Err(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    // requests run the day binaries directly, so they are all built up front.
+    let bins: Vec<String> = solved_puzzles().iter().map(Puzzle::bin_name).collect();
+    if let Err(e) = build_all(&bins, args.release) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+
+    let listener = match TcpListener::bind(("127.0.0.1", args.port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("could not listen on port {}: {}", args.port, e);
+            process::exit(1);
+        }
+    };
+    println!(
+        "Serving {} days on http://127.0.0.1:{}, e.g. `curl --data-binary @input.txt http://127.0.0.1:{}/day/1/part/1`",
+        bins.len(),
+        args.port,
+        args.port
+    );
+
+    let solver: Arc<Solver> = Arc::new(move |puzzle: &Puzzle, part: u8, input: &str| {
+        solve_with_binary(puzzle, part, input, &args)
+    });
+    serve(listener, solver);
+}
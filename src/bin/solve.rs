@@ -0,0 +1,155 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::summary;
+use advent_of_code::inputs::{named_inputs, named_inputs_dir, verdict, NamedInput, Verdict};
+use advent_of_code::{parse_answers, Puzzle, ANSI_BOLD, ANSI_RESET};
+use std::ffi::OsString;
+use std::process::{self, Command};
+
+struct Args {
+    puzzle: Puzzle,
+    release: bool,
+    all_inputs: bool,
+    // passed on to the day binary, e.g. `--record`.
+    rest: Vec<OsString>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let release = args.contains("--release");
+    let all_inputs = args.contains("--all-inputs");
+    Ok(Args {
+        puzzle: args.free_from_str()?,
+        release,
+        all_inputs,
+        rest: args.finish(),
+    })
+}
+
+fn day_command(args: &Args) -> Command {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--bin", &args.puzzle.bin_name()]);
+    if args.release {
+        cmd.arg("--release");
+    }
+    if cfg!(feature = "embed-inputs") {
+        cmd.args(["--features", "embed-inputs"]);
+    }
+    cmd.arg("--").args(&args.rest);
+    cmd
+}
+
+fn answer_cell(expected: &Option<String>, actual: &Option<String>, crashed: bool) -> String {
+    let actual_str = summary(actual.as_deref().unwrap_or_default());
+    match verdict(expected.as_deref(), actual.as_deref()) {
+        Verdict::Pass => format!("✓ {}", actual_str),
+        Verdict::Fail => format!(
+            "✗ {} (expected {})",
+            actual_str,
+            summary(expected.as_deref().unwrap_or_default())
+        ),
+        Verdict::Unknown => format!("? {}", actual_str),
+        Verdict::Missing if crashed => "✗ crashed".into(),
+        Verdict::Missing => match expected {
+            Some(expected) => format!("✗ not solved (expected {})", summary(expected)),
+            None => "- not solved".into(),
+        },
+    }
+}
+
+fn passed(expected: &Option<String>, actual: &Option<String>, crashed: bool) -> bool {
+    match verdict(expected.as_deref(), actual.as_deref()) {
+        Verdict::Fail => false,
+        // an unsolved part only passes if there is no answer it should have found.
+        Verdict::Missing => !crashed && expected.is_none(),
+        Verdict::Pass | Verdict::Unknown => true,
+    }
+}
+
+fn cross_validate(args: &Args) -> bool {
+    let inputs = named_inputs(&args.puzzle);
+    if inputs.is_empty() {
+        eprintln!(
+            "No inputs found for {}. Add them to \"{}\".",
+            args.puzzle,
+            named_inputs_dir(&args.puzzle).display()
+        );
+        return false;
+    }
+
+    let name_width = inputs.iter().map(|i| i.name.len()).max().unwrap().max(5);
+    println!(
+        "{}{:<width$}  {:<30}  Part 2{}",
+        ANSI_BOLD,
+        "Input",
+        "Part 1",
+        ANSI_RESET,
+        width = name_width
+    );
+
+    let mut all_passed = true;
+    for NamedInput {
+        name,
+        path,
+        expected,
+    } in &inputs
+    {
+        let (answers, crashed) = match day_command(args).arg("--input").arg(path).output() {
+            Ok(output) => (
+                parse_answers(&String::from_utf8_lossy(&output.stdout)),
+                !output.status.success(),
+            ),
+            Err(e) => {
+                eprintln!("failed to spawn cargo: {}", e);
+                ([None, None], true)
+            }
+        };
+
+        all_passed &= (0..2).all(|i| passed(&expected[i], &answers[i], crashed));
+
+        println!(
+            "{:<width$}  {:<30}  {}",
+            name,
+            answer_cell(&expected[0], &answers[0], crashed),
+            answer_cell(&expected[1], &answers[1], crashed),
+            width = name_width
+        );
+    }
+
+    println!("---");
+    println!("✓ matches known answer  ✗ wrong, missing or crash  ? no known answer");
+    all_passed
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!(
+                "Need to specify a day (e.g. `2022/7`). example: `cargo solve 2022/7` ({})",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    if args.all_inputs {
+        if args.rest.iter().any(|arg| arg == "--record") {
+            eprintln!(
+                "--record only records the default input and cannot be combined with --all-inputs."
+            );
+            process::exit(1);
+        }
+        process::exit(if cross_validate(&args) { 0 } else { 1 });
+    }
+
+    match day_command(&args).status() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!("failed to spawn cargo: {}", e);
+            process::exit(1);
+        }
+    }
+}
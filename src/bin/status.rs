@@ -0,0 +1,194 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{read_answer, summary};
+use advent_of_code::puzzle::years;
+use advent_of_code::timings::{format_duration, read_timing, Timing};
+use advent_of_code::{parse_test_outcome, Puzzle, ANSI_BOLD, ANSI_RESET};
+use std::fs;
+use std::path::Path;
+use std::process::{self, Command};
+
+struct Args {
+    run_tests: bool,
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        run_tests: args.contains(["-t", "--test"]),
+        year: args.opt_free_from_str()?,
+    })
+}
+
+#[derive(Clone, Copy)]
+enum FileStatus {
+    Missing,
+    Empty,
+    Present,
+}
+
+impl FileStatus {
+    fn of(path: &Path) -> Self {
+        match fs::metadata(path) {
+            Ok(meta) if meta.len() == 0 => FileStatus::Empty,
+            Ok(_) => FileStatus::Present,
+            Err(_) => FileStatus::Missing,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            FileStatus::Missing => "✗",
+            FileStatus::Empty => "○",
+            FileStatus::Present => "✓",
+        }
+    }
+}
+
+struct DayStatus {
+    module: FileStatus,
+    input: FileStatus,
+    example: FileStatus,
+    // one entry per part, `None` if tests were not run or did not compile.
+    tests: [Option<bool>; 2],
+    answers: [Option<String>; 2],
+    timing: Timing,
+}
+
+fn run_example_tests(puzzle: &Puzzle) -> [Option<bool>; 2] {
+    let output = match Command::new("cargo")
+        .args(["test", "--bin", &puzzle.bin_name()])
+        .output()
+    {
+        Ok(output) => String::from_utf8_lossy(&output.stdout).to_string(),
+        Err(e) => {
+            eprintln!("failed to run tests for day {}: {}", puzzle, e);
+            return [None, None];
+        }
+    };
+
+    [
+        parse_test_outcome(&output, "test_part_one"),
+        parse_test_outcome(&output, "test_part_two"),
+    ]
+}
+
+fn day_status(puzzle: &Puzzle, run_tests: bool) -> DayStatus {
+    let module = FileStatus::of(&puzzle.module_path());
+
+    let example = match FileStatus::of(&puzzle.data_path("examples")) {
+        // some days (e.g. 2022/09) split their example per part.
+        FileStatus::Missing => FileStatus::of(
+            &puzzle
+                .year_dir()
+                .join("examples")
+                .join(format!("{}_01.txt", puzzle.padded_day())),
+        ),
+        status => status,
+    };
+
+    let tests = if run_tests && matches!(module, FileStatus::Present) {
+        run_example_tests(puzzle)
+    } else {
+        [None, None]
+    };
+
+    DayStatus {
+        module,
+        input: FileStatus::of(&puzzle.data_path("inputs")),
+        example,
+        tests,
+        answers: [read_answer(puzzle, 1), read_answer(puzzle, 2)],
+        timing: read_timing(puzzle),
+    }
+}
+
+fn test_symbol(outcome: Option<bool>) -> &'static str {
+    match outcome {
+        Some(true) => "✓",
+        Some(false) => "✗",
+        None => "-",
+    }
+}
+
+fn answer_cell(answer: &Option<String>) -> String {
+    match answer {
+        Some(answer) => {
+            // grids show their size to keep the table aligned.
+            let answer = summary(answer);
+            if answer.chars().count() > 16 {
+                format!("{}…", answer.chars().take(15).collect::<String>())
+            } else {
+                answer
+            }
+        }
+        None => "-".into(),
+    }
+}
+
+fn time_cell(timing: &Timing, part: u8) -> String {
+    timing
+        .time_to(part)
+        .map(format_duration)
+        .unwrap_or_else(|| "-".into())
+}
+
+fn print_year(year: u16, run_tests: bool) {
+    println!(
+        "{}Day      Module  Input  Example  P1 test  P1 answer         P1 time  P2 test  P2 answer         P2 time{}",
+        ANSI_BOLD, ANSI_RESET
+    );
+
+    for puzzle in (1..=25).filter_map(|day| Puzzle::new(year, day)) {
+        let status = day_status(&puzzle, run_tests);
+        println!(
+            "{}  {:<6}  {:<5}  {:<7}  {:<7}  {:<16}  {:<7}  {:<7}  {:<16}  {}",
+            puzzle,
+            status.module.symbol(),
+            status.input.symbol(),
+            status.example.symbol(),
+            test_symbol(status.tests[0]),
+            answer_cell(&status.answers[0]),
+            time_cell(&status.timing, 1),
+            test_symbol(status.tests[1]),
+            answer_cell(&status.answers[1]),
+            time_cell(&status.timing, 2),
+        );
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let years: Vec<u16> = match args.year {
+        Some(year) => vec![year],
+        None => years(),
+    };
+
+    if years.is_empty() {
+        eprintln!("Could not find any year directories (e.g. \"src/y2022\"). Run `cargo status` from the repository root.");
+        process::exit(1);
+    }
+
+    for (idx, year) in years.into_iter().enumerate() {
+        if idx > 0 {
+            println!();
+        }
+        print_year(year, args.run_tests);
+    }
+
+    println!("---");
+    println!("✓ present  ○ empty  ✗ missing / failing  - not run / not recorded");
+    if !args.run_tests {
+        println!("🎄 Run `cargo status --test` to include example test results.");
+    }
+}
@@ -0,0 +1,169 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::calendar::{self, Clock, SystemClock, Today};
+use advent_of_code::Puzzle;
+use std::fs;
+use std::process::{self, Command};
+use std::time::Duration;
+
+struct Args {
+    max_wait: Duration,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let max_wait_minutes: u64 = if args.contains("--no-wait") {
+        0
+    } else {
+        args.opt_value_from_str("--max-wait")?.unwrap_or(60)
+    };
+    Ok(Args {
+        max_wait: Duration::from_secs(max_wait_minutes * 60),
+    })
+}
+
+fn run_command(bin: &str, puzzle: &Puzzle) -> bool {
+    match Command::new("cargo")
+        .args(["run", "--bin", bin, "--", &puzzle.to_string()])
+        .status()
+    {
+        Ok(status) => status.success(),
+        Err(e) => {
+            eprintln!("failed to spawn cargo: {}", e);
+            false
+        }
+    }
+}
+
+/// fetches the puzzle of the day: refuses if it is too far away, waits for it to unlock, then scaffolds
+/// its module and downloads its input unless they exist. `run_command` runs a cargo binary for the puzzle.
+fn fetch_today(
+    clock: &impl Clock,
+    max_wait: Duration,
+    mut run_command: impl FnMut(&str, &Puzzle) -> bool,
+) -> Result<Puzzle, String> {
+    let puzzle = match calendar::today(clock.now(), max_wait)? {
+        Today::Released(puzzle) => puzzle,
+        Today::Wait(puzzle, wait) => {
+            println!(
+                "⏳ Waiting {}m {}s for {} to unlock...",
+                wait.as_secs() / 60,
+                wait.as_secs() % 60,
+                puzzle
+            );
+            calendar::wait_for_unlock(clock, &puzzle);
+            puzzle
+        }
+    };
+
+    // never call aoc-cli for a puzzle that is not out yet.
+    if !calendar::is_released(&puzzle, clock.now()) {
+        return Err(format!("{} is not released yet.", puzzle));
+    }
+
+    if puzzle.module_path().exists() {
+        println!(
+            "Module \"{}\" exists, skipping scaffold.",
+            puzzle.module_path().display()
+        );
+    } else if !run_command("scaffold", &puzzle) {
+        return Err(format!("could not scaffold {}.", puzzle));
+    }
+
+    let has_input = fs::metadata(puzzle.data_path("inputs")).is_ok_and(|m| m.len() > 0);
+    if has_input {
+        println!(
+            "Input \"{}\" exists, skipping download.",
+            puzzle.data_path("inputs").display()
+        );
+    } else if !run_command("download", &puzzle) {
+        return Err(format!("could not download the input of {}.", puzzle));
+    }
+    Ok(puzzle)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = fetch_today(&SystemClock, args.max_wait, run_command) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::time::SystemTime;
+
+    struct FakeClock(Cell<SystemTime>);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> SystemTime {
+            self.0.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.0.set(self.0.get() + duration);
+        }
+    }
+
+    /// runs `fetch_today` at `offset` from the unlock of `puzzle`, returning the commands it ran.
+    fn fetch_at(
+        puzzle: &Puzzle,
+        offset: i64,
+        max_wait: Duration,
+    ) -> (Result<Puzzle, String>, Vec<String>) {
+        let unlock = calendar::unlock_time(puzzle);
+        let now = if offset < 0 {
+            unlock - Duration::from_secs(offset.unsigned_abs())
+        } else {
+            unlock + Duration::from_secs(offset as u64)
+        };
+        let clock = FakeClock(Cell::new(now));
+        let mut commands = vec![];
+        let result = fetch_today(&clock, max_wait, |bin, puzzle| {
+            commands.push(format!("{} {}", bin, puzzle));
+            true
+        });
+        (result, commands)
+    }
+
+    #[test]
+    fn test_fetch_today() {
+        // a year far enough ahead that it has neither modules nor inputs.
+        let puzzle = Puzzle::new(2090, 1).unwrap();
+        let hour = Duration::from_secs(3600);
+
+        let (result, commands) = fetch_at(&puzzle, -2 * 3600, hour);
+        assert!(result
+            .unwrap_err()
+            .starts_with("2090/01 is not released yet"));
+        assert!(commands.is_empty());
+
+        let (result, commands) = fetch_at(&puzzle, -30 * 60, hour);
+        assert_eq!(result, Ok(puzzle));
+        assert_eq!(commands, ["scaffold 2090/01", "download 2090/01"]);
+
+        let (result, commands) = fetch_at(&puzzle, 3600, Duration::ZERO);
+        assert_eq!(result, Ok(puzzle));
+        assert_eq!(commands, ["scaffold 2090/01", "download 2090/01"]);
+    }
+
+    #[test]
+    fn test_fetch_existing_day() {
+        let puzzle = Puzzle::new(2022, 5).unwrap();
+        let (result, commands) = fetch_at(&puzzle, 3600, Duration::ZERO);
+        assert_eq!(result, Ok(puzzle));
+        assert!(!commands.contains(&"scaffold 2022/05".to_string()));
+    }
+}
@@ -0,0 +1,226 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::Puzzle;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// puzzles unlock at midnight US Eastern (UTC-5).
+const UNLOCK_OFFSET_SECS: u64 = 5 * 60 * 60;
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// source of the current time, swapped out in tests.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// days since 1970-01-01 for a date of the proleptic gregorian calendar.
+/// see: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let month = month as i64;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// inverse of `days_from_civil`, returns `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+pub fn unlock_time(puzzle: &Puzzle) -> SystemTime {
+    let days = days_from_civil(puzzle.year as i64, 12, puzzle.day as u32) as u64;
+    UNIX_EPOCH + Duration::from_secs(days * SECS_PER_DAY + UNLOCK_OFFSET_SECS)
+}
+
+pub fn is_released(puzzle: &Puzzle, now: SystemTime) -> bool {
+    now >= unlock_time(puzzle)
+}
+
+/// the latest puzzle that unlocked at or before `now`, if `now` falls into an event.
+pub fn current_puzzle(now: SystemTime) -> Option<Puzzle> {
+    let (year, month, day) = eastern_date(now);
+    if month == 12 {
+        Puzzle::new(year as u16, day as u8)
+    } else {
+        None
+    }
+}
+
+/// the first puzzle that unlocks after `now`.
+pub fn next_puzzle(now: SystemTime) -> Puzzle {
+    let (year, month, day) = eastern_date(now);
+    let (year, day) = match (month, day) {
+        (12, day) if day < Puzzle::days_in(year as u16) as u32 => (year, day + 1),
+        (12, _) => (year + 1, 1),
+        _ => (year, 1),
+    };
+    Puzzle::new(year as u16, day as u8).expect("next puzzle is within the calendar")
+}
+
+fn eastern_date(now: SystemTime) -> (i64, u32, u32) {
+    let secs = now
+        .duration_since(UNIX_EPOCH)
+        .expect("time is after the unix epoch")
+        .as_secs();
+    civil_from_days(((secs - UNLOCK_OFFSET_SECS) / SECS_PER_DAY) as i64)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Today {
+    /// today's puzzle is already out.
+    Released(Puzzle),
+    /// the next puzzle unlocks within the allowed waiting time.
+    Wait(Puzzle, Duration),
+}
+
+/// works out which puzzle `cargo today` should fetch. refuses if the next puzzle is further than `max_wait` away.
+pub fn today(now: SystemTime, max_wait: Duration) -> Result<Today, String> {
+    if let Some(puzzle) = current_puzzle(now) {
+        let next = next_puzzle(now);
+        let wait = unlock_time(&next).duration_since(now).unwrap_or_default();
+        // right before midnight, the upcoming puzzle is the one we want.
+        if next.year == puzzle.year && next.day > puzzle.day && wait <= max_wait {
+            return Ok(Today::Wait(next, wait));
+        }
+        return Ok(Today::Released(puzzle));
+    }
+
+    let next = next_puzzle(now);
+    let wait = unlock_time(&next).duration_since(now).unwrap_or_default();
+    if wait <= max_wait {
+        Ok(Today::Wait(next, wait))
+    } else {
+        Err(format!(
+            "{} is not released yet, it unlocks in {}h {}m.",
+            next,
+            wait.as_secs() / 3600,
+            wait.as_secs() % 3600 / 60
+        ))
+    }
+}
+
+/// blocks until `puzzle` is released according to `clock`.
+pub fn wait_for_unlock(clock: &impl Clock, puzzle: &Puzzle) {
+    let unlock = unlock_time(puzzle);
+    while let Ok(remaining) = unlock.duration_since(clock.now()) {
+        if remaining.is_zero() {
+            break;
+        }
+        // sleep in slices so suspends or clock adjustments don't make us oversleep.
+        clock.sleep(remaining.min(Duration::from_secs(60)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct FakeClock(Cell<SystemTime>);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> SystemTime {
+            self.0.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.0.set(self.0.get() + duration);
+        }
+    }
+
+    fn utc(year: i64, month: u32, day: u32, hour: u64, minute: u64) -> SystemTime {
+        let days = days_from_civil(year, month, day) as u64;
+        UNIX_EPOCH + Duration::from_secs(days * SECS_PER_DAY + hour * 3600 + minute * 60)
+    }
+
+    #[test]
+    fn test_civil_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(civil_from_days(days_from_civil(2022, 12, 5)), (2022, 12, 5));
+        assert_eq!(civil_from_days(days_from_civil(2024, 2, 29)), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        let puzzle = Puzzle::new(2022, 5).unwrap();
+        assert_eq!(unlock_time(&puzzle), utc(2022, 12, 5, 5, 0));
+        assert!(!is_released(&puzzle, utc(2022, 12, 5, 4, 59)));
+        assert!(is_released(&puzzle, utc(2022, 12, 5, 5, 0)));
+    }
+
+    #[test]
+    fn test_today() {
+        let hour = Duration::from_secs(3600);
+
+        assert_eq!(
+            today(utc(2022, 12, 5, 12, 0), hour),
+            Ok(Today::Released(Puzzle::new(2022, 5).unwrap()))
+        );
+        // 23:30 eastern on the 4th waits for the 5th.
+        assert_eq!(
+            today(utc(2022, 12, 5, 4, 30), hour),
+            Ok(Today::Wait(
+                Puzzle::new(2022, 5).unwrap(),
+                Duration::from_secs(30 * 60)
+            ))
+        );
+        assert_eq!(
+            today(utc(2022, 12, 1, 4, 30), hour),
+            Ok(Today::Wait(
+                Puzzle::new(2022, 1).unwrap(),
+                Duration::from_secs(30 * 60)
+            ))
+        );
+        assert_eq!(
+            today(utc(2022, 12, 5, 4, 30), Duration::ZERO),
+            Ok(Today::Released(Puzzle::new(2022, 4).unwrap()))
+        );
+        assert!(today(utc(2022, 12, 27, 12, 0), hour).is_err());
+        assert!(today(utc(2022, 7, 1, 12, 0), hour).is_err());
+
+        // from 2025 on the event ends on the 12th.
+        assert_eq!(
+            today(utc(2025, 12, 12, 12, 0), hour),
+            Ok(Today::Released(Puzzle::new(2025, 12).unwrap()))
+        );
+        assert_eq!(
+            next_puzzle(utc(2025, 12, 12, 12, 0)),
+            Puzzle::new(2026, 1).unwrap()
+        );
+        assert!(today(utc(2025, 12, 13, 12, 0), hour).is_err());
+    }
+
+    #[test]
+    fn test_wait_for_unlock() {
+        let clock = FakeClock(Cell::new(utc(2022, 12, 5, 3, 0)));
+        wait_for_unlock(&clock, &Puzzle::new(2022, 5).unwrap());
+        assert_eq!(clock.now(), utc(2022, 12, 5, 5, 0));
+    }
+}
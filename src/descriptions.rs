@@ -0,0 +1,241 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::Puzzle;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// command that prints a puzzle description to stdout. `{year}` and `{day}` in `args` are filled in per puzzle.
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+impl Fetcher {
+    pub fn aoc_cli() -> Self {
+        Fetcher::from_command("aoc read --year {year} --day {day}").unwrap()
+    }
+
+    /// parses a whitespace-separated command line such as `./fetch.sh {year} {day}`.
+    pub fn from_command(command: &str) -> Option<Self> {
+        let mut parts = command.split_whitespace().map(String::from);
+        Some(Fetcher {
+            program: parts.next()?,
+            args: parts.collect(),
+        })
+    }
+
+    /// uses the command in `AOC_PUZZLE_FETCHER` if set, aoc-cli otherwise.
+    pub fn from_env() -> Self {
+        env::var("AOC_PUZZLE_FETCHER")
+            .ok()
+            .and_then(|command| Fetcher::from_command(&command))
+            .unwrap_or_else(Fetcher::aoc_cli)
+    }
+
+    pub fn fetch(&self, puzzle: &Puzzle) -> Result<String, String> {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| {
+                arg.replace("{year}", &puzzle.year.to_string())
+                    .replace("{day}", &puzzle.day.to_string())
+            })
+            .collect();
+
+        let output = Command::new(&self.program)
+            .args(&args)
+            .output()
+            .map_err(|e| format!("failed to spawn \"{}\": {}", self.program, e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "\"{} {}\" failed: {}",
+                self.program,
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let description = String::from_utf8_lossy(&output.stdout).to_string();
+        if description.trim().is_empty() {
+            return Err(format!(
+                "\"{}\" returned an empty description",
+                self.program
+            ));
+        }
+        Ok(description)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Archived {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+pub fn puzzles_dir(year: u16) -> PathBuf {
+    crate::puzzle::year_dir(year).join("puzzles")
+}
+
+pub fn description_path(dir: &Path, puzzle: &Puzzle) -> PathBuf {
+    dir.join(format!("{}.md", puzzle.padded_day()))
+}
+
+pub fn has_part_two(description: &str) -> bool {
+    description.contains(PART_TWO_HEADING)
+}
+
+/// extracts `Calorie Counting` from a heading such as `--- Day 1: Calorie Counting ---`.
+pub fn extract_title(description: &str) -> Option<String> {
+    description.lines().find_map(|line| {
+        let heading = line.split("--- Day ").nth(1)?;
+        let (_, title) = heading.split_once(": ")?;
+        let title = title.trim_end().trim_end_matches("---").trim();
+        (!title.is_empty()).then(|| title.to_string())
+    })
+}
+
+/// saves the description of `puzzle` to `dir`. descriptions that already contain part two are not fetched again unless `force` is set.
+pub fn archive(
+    fetcher: &Fetcher,
+    puzzle: &Puzzle,
+    dir: &Path,
+    force: bool,
+) -> Result<Archived, String> {
+    let path = description_path(dir, puzzle);
+    let existing = fs::read_to_string(&path).ok();
+
+    if !force && existing.as_deref().is_some_and(has_part_two) {
+        return Ok(Archived::Unchanged);
+    }
+
+    let description = fetcher.fetch(puzzle)?;
+    let outcome = match existing {
+        Some(existing) if existing == description => return Ok(Archived::Unchanged),
+        Some(_) => Archived::Updated,
+        None => Archived::Created,
+    };
+
+    fs::create_dir_all(dir)
+        .map_err(|e| format!("could not create \"{}\": {}", dir.display(), e))?;
+    fs::write(&path, description)
+        .map_err(|e| format!("could not write \"{}\": {}", path.display(), e))?;
+    Ok(outcome)
+}
+
+/// rewrites `index.md` in `dir` with the title of every archived description.
+pub fn write_index(dir: &Path, year: u16) -> Result<PathBuf, String> {
+    let mut rows = vec![];
+    for puzzle in (1..=25).filter_map(|day| Puzzle::new(year, day)) {
+        let path = description_path(dir, &puzzle);
+        let Ok(description) = fs::read_to_string(&path) else {
+            continue;
+        };
+        rows.push(format!(
+            "| [{}]({}.md) | {} | {} |",
+            puzzle.padded_day(),
+            puzzle.padded_day(),
+            extract_title(&description).unwrap_or_else(|| "?".into()),
+            if has_part_two(&description) {
+                "✓"
+            } else {
+                ""
+            }
+        ));
+    }
+
+    let index = format!(
+        "# Advent of Code {}\n\n| Day | Title | Part Two |\n| --- | --- | --- |\n{}\n",
+        year,
+        rows.join("\n")
+    );
+    let path = dir.join("index.md");
+    fs::write(&path, index)
+        .map_err(|e| format!("could not write \"{}\": {}", path.display(), e))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE: &str = "## --- Day 17: Pyroclastic Flow ---\n\nThe rocks fall.\n";
+
+    #[test]
+    fn test_extract_title() {
+        assert_eq!(extract_title(PART_ONE), Some("Pyroclastic Flow".into()));
+        assert_eq!(
+            extract_title("--- Day 1: Calorie Counting ---"),
+            Some("Calorie Counting".into())
+        );
+        assert_eq!(extract_title("no heading"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_archive_with_fake_aoc() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = env::temp_dir().join(format!("aoc_descriptions_{}", std::process::id()));
+        let puzzles = dir.join("puzzles");
+        fs::create_dir_all(&dir).unwrap();
+
+        // the fake `aoc` prints whatever the test put into `served.md`.
+        let served = dir.join("served.md");
+        let script = dir.join("aoc");
+        fs::write(
+            &script,
+            format!("#!/bin/sh\ncat \"{}\"\n", served.display()),
+        )
+        .unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let fetcher = Fetcher {
+            program: script.to_string_lossy().to_string(),
+            args: vec!["read".into(), "--day".into(), "{day}".into()],
+        };
+        let puzzle = Puzzle::new(2022, 17).unwrap();
+
+        fs::write(&served, PART_ONE).unwrap();
+        assert_eq!(
+            archive(&fetcher, &puzzle, &puzzles, false),
+            Ok(Archived::Created)
+        );
+        assert_eq!(
+            archive(&fetcher, &puzzle, &puzzles, false),
+            Ok(Archived::Unchanged)
+        );
+
+        let both_parts = format!("{}\n## {}\n\nMore rocks.\n", PART_ONE, PART_TWO_HEADING);
+        fs::write(&served, &both_parts).unwrap();
+        assert_eq!(
+            archive(&fetcher, &puzzle, &puzzles, false),
+            Ok(Archived::Updated)
+        );
+        assert_eq!(
+            fs::read_to_string(description_path(&puzzles, &puzzle)).unwrap(),
+            both_parts
+        );
+
+        // once part two is archived, the fetcher is not called anymore.
+        fs::remove_file(&served).unwrap();
+        assert_eq!(
+            archive(&fetcher, &puzzle, &puzzles, false),
+            Ok(Archived::Unchanged)
+        );
+        assert!(archive(&fetcher, &puzzle, &puzzles, true).is_err());
+
+        let index = fs::read_to_string(write_index(&puzzles, 2022).unwrap()).unwrap();
+        assert!(index.contains("| [17](17.md) | Pyroclastic Flow | ✓ |"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
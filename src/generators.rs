@@ -0,0 +1,107 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::Puzzle;
+
+pub mod y2022;
+
+/// builds a puzzle input of roughly `size` units (lines, blocks or grid rows - see the generator) from `rng`.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// the generator registered for `puzzle`, if there is one.
+pub fn generator(puzzle: &Puzzle) -> Option<Generator> {
+    match puzzle.year {
+        2022 => y2022::generator(puzzle.day),
+        _ => None,
+    }
+}
+
+pub fn generate(puzzle: &Puzzle, size: usize, seed: u64) -> Option<String> {
+    generator(puzzle).map(|generate| generate(&mut Rng::new(seed), size))
+}
+
+/// a generated input of `size` for the tests of a day, e.g. to check that its parser accepts small and large inputs.
+/// panics if the puzzle has no generator.
+pub fn generated_input(year: u16, day: u8, size: usize) -> String {
+    let puzzle = Puzzle::new(year, day).expect("invalid puzzle");
+    generate(&puzzle, size, 1).unwrap_or_else(|| panic!("no generator for {}", puzzle))
+}
+
+/// small, seedable PRNG (splitmix64) so generated inputs are reproducible without extra dependencies.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// uniform integer in `lo..=hi`.
+    pub fn int(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "empty range {}..={}", lo, hi);
+        let span = (hi - lo) as u64 + 1;
+        lo + (self.next_u64() % span) as i64
+    }
+
+    /// uniform index in `0..len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "cannot pick from an empty range");
+        (self.next_u64() % len as u64) as usize
+    }
+
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.next_u64() % denominator < numerator
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_reproducible() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        assert!((0..100).all(|_| a.next_u64() == b.next_u64()));
+        assert!((0..100).all(|_| (-3..=3).contains(&a.int(-3, 3))));
+    }
+
+    #[test]
+    fn test_generate_is_reproducible() {
+        for day in 1..=25 {
+            let puzzle = Puzzle::new(2022, day).unwrap();
+            if generator(&puzzle).is_none() {
+                continue;
+            }
+            let input = generate(&puzzle, 20, 7).unwrap();
+            assert!(!input.trim().is_empty(), "day {} generated no input", day);
+            assert_eq!(Some(input), generate(&puzzle, 20, 7), "day {}", day);
+            assert_ne!(
+                generate(&puzzle, 20, 7),
+                generate(&puzzle, 20, 8),
+                "day {}",
+                day
+            );
+        }
+    }
+}
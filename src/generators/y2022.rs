@@ -0,0 +1,495 @@
+/*
+ * Input generators for the 2022 puzzles.
+ * Every generator produces input in the format of the real puzzle input, including the trailing newline.
+ */
+use super::{Generator, Rng};
+use std::fmt::Write;
+
+pub fn generator(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => calories,
+        2 => strategy_guide,
+        3 => rucksacks,
+        4 => section_assignments,
+        5 => crate_stacks,
+        6 => datastream,
+        7 => terminal_output,
+        8 => tree_grid,
+        9 => rope_motions,
+        10 => cpu_program,
+        11 => monkeys,
+        12 => heightmap,
+        13 => packet_pairs,
+        14 => rock_paths,
+        15 => sensors,
+        17 => jet_pattern,
+        18 => cubes,
+        19 => blueprints,
+        20 => encrypted_file,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+/// a random lowercase word of `min..=max` letters.
+fn word(rng: &mut Rng, min: i64, max: i64) -> String {
+    (0..rng.int(min, max))
+        .map(|_| (b'a' + rng.index(26) as u8) as char)
+        .collect()
+}
+
+/// `size` elves carrying 1-6 snacks each.
+fn calories(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            (0..rng.int(1, 6))
+                .map(|_| format!("{}\n", rng.int(1000, 60000)))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `size` rounds of rock paper scissors.
+fn strategy_guide(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.pick(&['A', 'B', 'C']),
+                rng.pick(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
+
+/// `size` rucksacks, rounded up to full groups of three. each rucksack has exactly one item in both
+/// compartments and each group shares exactly one badge.
+fn rucksacks(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    let alphabet: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+
+    for _ in 0..size.max(1).div_ceil(3) {
+        let mut items = alphabet.clone();
+        rng.shuffle(&mut items);
+        let badge = items[0];
+
+        // every rucksack of the group draws from its own items so only the badge is shared by all three.
+        for pool in items[1..].chunks(17).take(3) {
+            let shared = pool[0];
+            let (left_pool, right_pool) = pool[1..].split_at(8);
+            let len = rng.int(4, 16) as usize;
+
+            let mut compartments = [left_pool, right_pool].map(|pool| {
+                let mut compartment: Vec<char> = (0..len - 1).map(|_| *rng.pick(pool)).collect();
+                compartment.push(shared);
+                compartment
+            });
+            let badge_side = rng.index(2);
+            compartments[badge_side][0] = badge;
+
+            for compartment in &mut compartments {
+                rng.shuffle(compartment);
+                out.extend(compartment.iter());
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// `size` pairs of section ranges.
+fn section_assignments(rng: &mut Rng, size: usize) -> String {
+    let range = |rng: &mut Rng| {
+        let lower = rng.int(1, 99);
+        format!("{}-{}", lower, rng.int(lower, 99))
+    };
+    (0..size.max(1))
+        .map(|_| format!("{},{}\n", range(rng), range(rng)))
+        .collect()
+}
+
+/// 3-9 stacks of crates followed by `size` moves. moves never empty a stack.
+fn crate_stacks(rng: &mut Rng, size: usize) -> String {
+    let mut stacks: Vec<Vec<char>> = (0..rng.int(3, 9))
+        .map(|_| word(rng, 2, 8).to_uppercase().chars().collect())
+        .collect();
+
+    let mut out = String::new();
+    let height = stacks.iter().map(|s| s.len()).max().unwrap();
+    for row in (0..height).rev() {
+        let line: Vec<String> = stacks
+            .iter()
+            .map(|s| match s.get(row) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".into(),
+            })
+            .collect();
+        writeln!(out, "{}", line.join(" ")).unwrap();
+    }
+    let labels: Vec<String> = (1..=stacks.len()).map(|i| format!(" {} ", i)).collect();
+    writeln!(out, "{}\n", labels.join(" ")).unwrap();
+
+    for _ in 0..size.max(1) {
+        // there are more crates than stacks, so some stack always holds at least two.
+        let candidates: Vec<usize> = (0..stacks.len()).filter(|&i| stacks[i].len() > 1).collect();
+        let from = *rng.pick(&candidates);
+        let to = (from + 1 + rng.index(stacks.len() - 1)) % stacks.len();
+        let count = rng.int(1, stacks[from].len() as i64 - 1) as usize;
+
+        let split = stacks[from].len() - count;
+        let moved: Vec<char> = stacks[from].drain(split..).collect();
+        stacks[to].extend(moved);
+        writeln!(out, "move {} from {} to {}", count, from + 1, to + 1).unwrap();
+    }
+    out
+}
+
+/// a datastream of at least `size` characters with a start-of-message marker at a random position.
+fn datastream(rng: &mut Rng, size: usize) -> String {
+    let mut alphabet: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut alphabet);
+
+    // three letters never form a start-of-packet marker on their own.
+    let len = size.max(20);
+    let mut stream: Vec<char> = (0..len).map(|_| *rng.pick(&alphabet[..3])).collect();
+    let marker_at = rng.index(len - 14 + 1);
+    rng.shuffle(&mut alphabet);
+    stream.splice(marker_at..marker_at + 14, alphabet[..14].iter().copied());
+
+    let mut out: String = stream.into_iter().collect();
+    out.push('\n');
+    out
+}
+
+/// a terminal session exploring a filesystem with `size` directories, using 40-69M of the 70M disk.
+fn terminal_output(rng: &mut Rng, size: usize) -> String {
+    struct Dir {
+        name: String,
+        dirs: Vec<usize>,
+        files: Vec<(String, u64)>,
+    }
+
+    let mut dirs = vec![Dir {
+        name: "/".into(),
+        dirs: vec![],
+        files: vec![],
+    }];
+
+    for i in 1..size.max(1) {
+        let parent = rng.index(i);
+        // the index keeps sibling names unique.
+        let name = format!("{}{}", word(rng, 1, 6), i);
+        dirs.push(Dir {
+            name,
+            dirs: vec![],
+            files: vec![],
+        });
+        dirs[parent].dirs.push(i);
+    }
+
+    for (i, dir) in dirs.iter_mut().enumerate() {
+        let count = if i == 0 { rng.int(1, 4) } else { rng.int(0, 4) };
+        for j in 0..count {
+            let ext = rng.pick(&["", ".txt", ".dat", ".lst", ".log"]);
+            let name = format!("{}{}{}", word(rng, 1, 6), j, ext);
+            dir.files.push((name, rng.int(1000, 100000) as u64));
+        }
+    }
+
+    // most files are small so some directories stay below 100000, a few large ones fill up the disk.
+    let total: u64 = dirs.iter().flat_map(|d| &d.files).map(|f| f.1).sum();
+    let target = rng.int(40_100_000, 69_000_000) as u64;
+    if total > target {
+        for dir in &mut dirs {
+            for file in &mut dir.files {
+                file.1 = (file.1 * target / total).max(1);
+            }
+        }
+    } else {
+        let fillers = (dirs.len() / 10).max(1) as u64;
+        for k in 0..fillers {
+            let dir = rng.index(dirs.len());
+            let size = (target - total) / fillers
+                + if k == 0 {
+                    (target - total) % fillers
+                } else {
+                    0
+                };
+            let name = format!("{}.big{}", word(rng, 1, 6), k);
+            dirs[dir].files.push((name, size));
+        }
+    }
+
+    fn walk(dirs: &[Dir], idx: usize, rng: &mut Rng, out: &mut String) {
+        let dir = &dirs[idx];
+        writeln!(out, "$ cd {}", dir.name).unwrap();
+        writeln!(out, "$ ls").unwrap();
+
+        let mut listing: Vec<String> = dir
+            .dirs
+            .iter()
+            .map(|&d| format!("dir {}", dirs[d].name))
+            .chain(
+                dir.files
+                    .iter()
+                    .map(|(name, size)| format!("{} {}", size, name)),
+            )
+            .collect();
+        rng.shuffle(&mut listing);
+        for line in listing {
+            writeln!(out, "{}", line).unwrap();
+        }
+
+        for &child in &dir.dirs {
+            walk(dirs, child, rng, out);
+            writeln!(out, "$ cd ..").unwrap();
+        }
+    }
+
+    let mut out = String::new();
+    walk(&dirs, 0, rng, &mut out);
+    out
+}
+
+/// a `size` x `size` grid of tree heights.
+fn tree_grid(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| char::from(b'0' + rng.index(10) as u8))
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+/// `size` head motions.
+fn rope_motions(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| format!("{} {}\n", rng.pick(&['U', 'D', 'L', 'R']), rng.int(1, 19)))
+        .collect()
+}
+
+/// a program running for exactly the 240 cycles of the CRT. `size` is ignored.
+/// the X register stays in `1..=36` so the sprite never wraps around the screen edge.
+fn cpu_program(rng: &mut Rng, _size: usize) -> String {
+    let mut out = String::new();
+    let mut cycles = 0;
+    let mut x = 1;
+
+    while cycles < 240 {
+        let target = rng.int(1.max(x - 15), 36.min(x + 15));
+        if cycles == 239 || target == x || rng.chance(1, 3) {
+            out.push_str("noop\n");
+            cycles += 1;
+        } else {
+            writeln!(out, "addx {}", target - x).unwrap();
+            x = target;
+            cycles += 2;
+        }
+    }
+    out
+}
+
+/// `size` monkeys (2-10) with distinct prime divisors, at most one squaring monkey and mostly additions.
+fn monkeys(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 10);
+    let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    rng.shuffle(&mut primes);
+
+    let mut operations: Vec<String> = (0..count)
+        .map(|i| match i {
+            0 if count > 2 => "old * old".to_string(),
+            i if i <= count / 4 => format!("old * {}", rng.int(2, 19)),
+            _ => format!("old + {}", rng.int(1, 8)),
+        })
+        .collect();
+    rng.shuffle(&mut operations);
+
+    (0..count)
+        .map(|i| {
+            let items: Vec<String> = (0..rng.int(1, 6) + (size / 8) as i64)
+                .map(|_| rng.int(50, 99).to_string())
+                .collect();
+            let if_true = (i + 1 + rng.index(count - 1)) % count;
+            let if_false = loop {
+                let target = rng.index(count);
+                if target != i && (target != if_true || count == 2) {
+                    break target;
+                }
+            };
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                i,
+                items.join(", "),
+                operations[i],
+                primes[i],
+                if_true,
+                if_false
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// a heightmap with `size` rows (at least 8) that rises towards `E`, which is always reachable from `S`.
+fn heightmap(rng: &mut Rng, size: usize) -> String {
+    let rows = size.max(8) as i64;
+    let cols = (size * 2).max(48) as i64;
+    let end = (rng.int(0, rows - 1), rng.int(0, cols - 1));
+    let distance = |(r, c): (i64, i64)| (r - end.0).abs() + (c - end.1).abs();
+
+    let corners = [(0, 0), (0, cols - 1), (rows - 1, 0), (rows - 1, cols - 1)];
+    let start = *corners.iter().max_by_key(|&&p| distance(p)).unwrap();
+    let max_distance = distance(start);
+
+    let mut out = String::new();
+    for r in 0..rows {
+        for c in 0..cols {
+            // at most one level per step, so there is always a way up.
+            let level = 25 - distance((r, c)) * 26 / (max_distance + 1);
+            out.push(match (r, c) {
+                p if p == start => 'S',
+                p if p == end => 'E',
+                _ => (b'a' + level as u8) as char,
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// `size` pairs of packets.
+fn packet_pairs(rng: &mut Rng, size: usize) -> String {
+    fn packet(rng: &mut Rng, depth: usize) -> String {
+        let items: Vec<String> = (0..rng.int(0, 4))
+            .map(|_| {
+                if depth < 3 && rng.chance(1, 3) {
+                    packet(rng, depth + 1)
+                } else {
+                    rng.int(0, 10).to_string()
+                }
+            })
+            .collect();
+        format!("[{}]", items.join(","))
+    }
+
+    (0..size.max(1))
+        .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `size` rock paths below the sand source at `500,0`. day 14 reads unsigned coordinates, so `x` stays at 0 or above.
+fn rock_paths(rng: &mut Rng, size: usize) -> String {
+    let spread = (size as i64 + 10).min(450);
+    let depth = (size as i64 + 20).min(300);
+
+    (0..size.max(1))
+        .map(|_| {
+            let mut point = (rng.int(500 - spread, 500 + spread), rng.int(2, depth));
+            let mut points = vec![point];
+            for i in 0..rng.int(1, 4) {
+                let step = rng.int(1, 8) * if rng.chance(1, 2) { 1 } else { -1 };
+                if i % 2 == 0 {
+                    point.0 = (point.0 + step).max(0);
+                } else {
+                    point.1 = (point.1 + step).clamp(2, depth);
+                }
+                if point != *points.last().unwrap() {
+                    points.push(point);
+                }
+            }
+            if points.len() == 1 {
+                points.push((point.0 + 1, point.1));
+            }
+            let path: Vec<String> = points.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+            format!("{}\n", path.join(" -> "))
+        })
+        .collect()
+}
+
+/// `size` sensors spread over the 4M x 4M search area, each reporting its unique closest beacon.
+fn sensors(rng: &mut Rng, size: usize) -> String {
+    let coordinate = |rng: &mut Rng| (rng.int(0, 4_000_000), rng.int(0, 4_000_000));
+    let distance = |a: (i64, i64), b: (i64, i64)| (a.0 - b.0).abs() + (a.1 - b.1).abs();
+
+    let beacons: Vec<(i64, i64)> = (0..(size / 2).max(1)).map(|_| coordinate(rng)).collect();
+    let mut out = String::new();
+    let mut placed = 0;
+
+    while placed < size.max(1) {
+        let sensor = coordinate(rng);
+        let mut by_distance: Vec<(i64, (i64, i64))> =
+            beacons.iter().map(|&b| (distance(sensor, b), b)).collect();
+        by_distance.sort_unstable();
+        if by_distance[0].0 == 0 || by_distance.get(1).is_some_and(|b| b.0 == by_distance[0].0) {
+            continue;
+        }
+        let beacon = by_distance[0].1;
+        writeln!(
+            out,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            sensor.0, sensor.1, beacon.0, beacon.1
+        )
+        .unwrap();
+        placed += 1;
+    }
+    out
+}
+
+/// `size` jets of hot gas.
+fn jet_pattern(rng: &mut Rng, size: usize) -> String {
+    let mut out: String = (0..size.max(1)).map(|_| *rng.pick(&['<', '>'])).collect();
+    out.push('\n');
+    out
+}
+
+/// `size` distinct cubes packed into a bounding box just large enough to hold them.
+fn cubes(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let bound = ((size * 2) as f64).cbrt().ceil() as i64 + 1;
+    let mut seen = std::collections::HashSet::new();
+    let mut out = String::new();
+
+    while seen.len() < size {
+        let cube = (rng.int(1, bound), rng.int(1, bound), rng.int(1, bound));
+        if seen.insert(cube) {
+            writeln!(out, "{},{},{}", cube.0, cube.1, cube.2).unwrap();
+        }
+    }
+    out
+}
+
+/// `size` robot factory blueprints.
+fn blueprints(rng: &mut Rng, size: usize) -> String {
+    (1..=size.max(1))
+        .map(|i| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                i,
+                rng.int(2, 4),
+                rng.int(2, 4),
+                rng.int(2, 4),
+                rng.int(5, 20),
+                rng.int(2, 4),
+                rng.int(5, 20)
+            )
+        })
+        .collect()
+}
+
+/// `size` numbers with plenty of duplicates and exactly one `0`.
+fn encrypted_file(rng: &mut Rng, size: usize) -> String {
+    let mut numbers: Vec<i64> = (0..size.max(2) - 1)
+        .map(|_| match rng.int(-10000, 9999) {
+            0 => 10000,
+            n => n,
+        })
+        .collect();
+    numbers.insert(rng.index(numbers.len() + 1), 0);
+    numbers.iter().map(|n| format!("{}\n", n)).collect()
+}
@@ -2,3 +2,23 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+mod bitset;
+mod cycle;
+mod graph;
+mod grid;
+mod interval;
+pub mod math;
+pub mod parse;
+mod point;
+mod progress;
+pub mod search;
+mod voxel;
+
+pub use bitset::{letter_index, BitSet, BitSet128, BitSet64};
+pub use cycle::{find_cycle, Cycle};
+pub use graph::{Distances, Graph, NodeId};
+pub use grid::{Grid, Pos, OFFSETS_4, OFFSETS_8};
+pub use interval::{Interval, IntervalSet};
+pub use point::{Direction, Num, Point2, Point3, Vec2};
+pub use progress::Progress;
+pub use voxel::{Voxel, VoxelGrid};
@@ -0,0 +1,359 @@
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Sub};
+
+/// the bit of an ASCII letter: `a-z` are 0 to 25, `A-Z` are 26 to 51.
+/// this is the item priority of 2022/03 minus one.
+pub fn letter_index(c: char) -> Option<usize> {
+    match c {
+        'a'..='z' => Some(c as usize - 'a' as usize),
+        'A'..='Z' => Some(c as usize - 'A' as usize + 26),
+        _ => None,
+    }
+}
+
+macro_rules! fixed_bitset {
+    ($name:ident, $bits:ty, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name($bits);
+
+        impl $name {
+            pub const CAPACITY: usize = <$bits>::BITS as usize;
+            pub const EMPTY: Self = $name(0);
+
+            pub const fn new() -> Self {
+                $name(0)
+            }
+
+            /// the set with the bits of `bits` set.
+            pub const fn from_bits(bits: $bits) -> Self {
+                $name(bits)
+            }
+
+            pub const fn bits(self) -> $bits {
+                self.0
+            }
+
+            fn bit(value: usize) -> $bits {
+                assert!(
+                    value < Self::CAPACITY,
+                    "{} does not fit {}",
+                    value,
+                    stringify!($name)
+                );
+                1 << value
+            }
+
+            /// returns whether `value` was not in the set yet.
+            pub fn insert(&mut self, value: usize) -> bool {
+                let inserted = !self.contains(value);
+                self.0 |= Self::bit(value);
+                inserted
+            }
+
+            /// returns whether `value` was in the set.
+            pub fn remove(&mut self, value: usize) -> bool {
+                let removed = self.contains(value);
+                self.0 &= !Self::bit(value);
+                removed
+            }
+
+            pub fn contains(self, value: usize) -> bool {
+                value < Self::CAPACITY && self.0 & Self::bit(value) != 0
+            }
+
+            /// the number of values in the set.
+            pub fn len(self) -> usize {
+                self.0.count_ones() as usize
+            }
+
+            pub fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            pub fn union(self, other: Self) -> Self {
+                $name(self.0 | other.0)
+            }
+
+            pub fn intersection(self, other: Self) -> Self {
+                $name(self.0 & other.0)
+            }
+
+            pub fn difference(self, other: Self) -> Self {
+                $name(self.0 & !other.0)
+            }
+
+            pub fn is_subset(self, other: Self) -> bool {
+                self.difference(other).is_empty()
+            }
+
+            /// the values in ascending order.
+            pub fn iter(self) -> impl Iterator<Item = usize> {
+                let mut bits = self.0;
+                std::iter::from_fn(move || {
+                    (bits != 0).then(|| {
+                        let value = bits.trailing_zeros() as usize;
+                        bits &= bits - 1;
+                        value
+                    })
+                })
+            }
+
+            /// the letters of `text` as in `letter_index`, e.g. the item types of a rucksack.
+            pub fn from_letters(text: &str) -> Result<Self, String> {
+                text.chars()
+                    .map(|c| {
+                        letter_index(c).ok_or_else(|| format!("{:?} is not an ASCII letter", c))
+                    })
+                    .collect()
+            }
+        }
+
+        impl FromIterator<usize> for $name {
+            fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+                let mut set = $name::new();
+                for value in iter {
+                    set.insert(value);
+                }
+                set
+            }
+        }
+
+        impl BitOr for $name {
+            type Output = $name;
+
+            fn bitor(self, rhs: Self) -> Self::Output {
+                self.union(rhs)
+            }
+        }
+
+        impl BitAnd for $name {
+            type Output = $name;
+
+            fn bitand(self, rhs: Self) -> Self::Output {
+                self.intersection(rhs)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                self.difference(rhs)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_set().entries(self.iter()).finish()
+            }
+        }
+    };
+}
+
+fixed_bitset!(
+    BitSet64,
+    u64,
+    "a set of the integers below 64, stored as the bits of a single `u64`."
+);
+fixed_bitset!(
+    BitSet128,
+    u128,
+    "a set of the integers below 128, stored as the bits of a single `u128`."
+);
+
+/// a set of integers of any size, growing as larger values are inserted, e.g. the visited states of a search.
+#[derive(Clone, Default, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        BitSet { words: vec![] }
+    }
+
+    /// an empty set with room for the values below `capacity` without growing.
+    pub fn with_capacity(capacity: usize) -> Self {
+        BitSet {
+            words: vec![0; capacity.div_ceil(64)],
+        }
+    }
+
+    /// returns whether `value` was not in the set yet.
+    pub fn insert(&mut self, value: usize) -> bool {
+        let (word, bit) = (value / 64, 1 << (value % 64));
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let inserted = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        inserted
+    }
+
+    /// returns whether `value` was in the set.
+    pub fn remove(&mut self, value: usize) -> bool {
+        let removed = self.contains(value);
+        if removed {
+            self.words[value / 64] &= !(1 << (value % 64));
+        }
+        removed
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        self.words
+            .get(value / 64)
+            .is_some_and(|word| word & (1 << (value % 64)) != 0)
+    }
+
+    /// the number of values in the set.
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let (longer, shorter) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut words = longer.words.clone();
+        for (word, other) in words.iter_mut().zip(&shorter.words) {
+            *word |= other;
+        }
+        BitSet { words }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let words = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| a & b)
+            .collect();
+        BitSet { words }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let words = self
+            .words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| word & !other.words.get(i).unwrap_or(&0))
+            .collect();
+        BitSet { words }
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// the values in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            BitSet64::from_bits(word)
+                .iter()
+                .map(move |bit| i * 64 + bit)
+        })
+    }
+}
+
+/// sets are equal by their values, however far they have grown.
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_subset(other) && other.is_subset(self)
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = BitSet::new();
+        for value in iter {
+            set.insert(value);
+        }
+        set
+    }
+}
+
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed() {
+        let mut set = BitSet64::new();
+        assert!(set.insert(3) && set.insert(63) && !set.insert(3));
+        assert!(set.contains(63) && !set.contains(4) && !set.contains(64));
+        assert_eq!(set.len(), 2);
+        assert!(set.remove(3) && !set.remove(3));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [63]);
+
+        let a: BitSet128 = [1, 5, 100].into_iter().collect();
+        let b: BitSet128 = [5, 100, 127].into_iter().collect();
+        assert_eq!((a | b).iter().collect::<Vec<_>>(), [1, 5, 100, 127]);
+        assert_eq!((a & b).iter().collect::<Vec<_>>(), [5, 100]);
+        assert_eq!((a - b).iter().collect::<Vec<_>>(), [1]);
+        assert!((a & b).is_subset(a) && !a.is_subset(b));
+        assert_eq!(format!("{:?}", a), "{1, 5, 100}");
+    }
+
+    #[test]
+    #[should_panic(expected = "64 does not fit BitSet64")]
+    fn test_fixed_overflow() {
+        BitSet64::new().insert(64);
+    }
+
+    #[test]
+    fn test_letters() {
+        assert_eq!(letter_index('a'), Some(0));
+        assert_eq!(letter_index('Z'), Some(51));
+        assert_eq!(letter_index('1'), None);
+
+        let items = BitSet64::from_letters("vJrwpWtwJgWr").unwrap();
+        let other = BitSet64::from_letters("hcsFMMfFFhFp").unwrap();
+        assert_eq!((items & other).iter().collect::<Vec<_>>(), [15]);
+        assert_eq!(
+            BitSet64::from_letters("ab1"),
+            Err("'1' is not an ASCII letter".into())
+        );
+    }
+
+    #[test]
+    fn test_growable() {
+        let mut set = BitSet::new();
+        assert!(set.insert(1000) && set.insert(2) && !set.insert(2));
+        assert!(set.contains(1000) && !set.contains(999) && !set.contains(5000));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [2, 1000]);
+
+        let small: BitSet = [2, 3].into_iter().collect();
+        assert_eq!(set.union(&small).len(), 3);
+        assert_eq!(set.intersection(&small), [2].into_iter().collect());
+        assert_eq!(set.difference(&small), [1000].into_iter().collect());
+        assert!(set.remove(1000));
+        // equal despite the extra words left over from 1000.
+        assert_eq!(
+            set,
+            BitSet::with_capacity(3).union(&[2].into_iter().collect())
+        );
+        set.clear();
+        assert!(set.is_empty());
+    }
+}
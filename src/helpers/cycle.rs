@@ -0,0 +1,121 @@
+use super::Num;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// a simulation that repeats itself: after `start` steps, it returns to the same state every `length` steps.
+/// `values[i]` is the tracked quantity after `i` steps, for every step up to the first repetition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<V> {
+    pub start: usize,
+    pub length: usize,
+    pub values: Vec<V>,
+}
+
+impl<V: Num + TryFrom<usize>> Cycle<V> {
+    /// the tracked quantity after `step` steps, assuming it grows by the same amount every cycle.
+    /// e.g. the height of a tower after 1,000,000,000,000 rocks.
+    pub fn value_at(&self, step: usize) -> V {
+        if step < self.values.len() {
+            return self.values[step];
+        }
+        let cycles = (step - self.start) / self.length;
+        let offset = (step - self.start) % self.length;
+        let gain = self.values[self.start + self.length] - self.values[self.start];
+        let cycles = V::try_from(cycles)
+            .unwrap_or_else(|_| panic!("{} cycles do not fit the tracked value", cycles));
+        self.values[self.start + offset] + gain * cycles
+    }
+}
+
+/// runs `step` on `state` until `observe` returns a key it returned before, at most `max_steps` times.
+/// `observe` returns the key that identifies a state, e.g. the shape of the top of a tower, and the quantity
+/// to track, e.g. the height of the tower. returns `None` if no state repeated within `max_steps`.
+///
+/// ```
+/// use advent_of_code::helpers::find_cycle;
+///
+/// // squares a digit and adds one, summing up the digits: 3, 0, 1, 2, 5, 6, 7, 0, ...
+/// let step = |(digit, sum): &mut (u64, u64)| {
+///     *digit = (*digit * *digit + 1) % 10;
+///     *sum += *digit;
+/// };
+/// let cycle = find_cycle((3, 0), 100, step, |&(digit, sum)| (digit, sum)).unwrap();
+/// assert_eq!((cycle.start, cycle.length), (1, 6));
+/// assert_eq!(cycle.value_at(6_000_001), 21_000_000);
+/// ```
+pub fn find_cycle<S, K, V>(
+    mut state: S,
+    max_steps: usize,
+    mut step: impl FnMut(&mut S),
+    mut observe: impl FnMut(&S) -> (K, V),
+) -> Option<Cycle<V>>
+where
+    K: Hash + Eq,
+{
+    let mut seen = HashMap::new();
+    let mut values = vec![];
+    for i in 0..=max_steps {
+        let (key, value) = observe(&state);
+        values.push(value);
+        if let Some(start) = seen.insert(key, i) {
+            return Some(Cycle {
+                start,
+                length: i - start,
+                values,
+            });
+        }
+        step(&mut state);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a walk over the digits, summing up the digits it visits.
+    fn walk(state: &mut (u64, u64)) {
+        state.0 = (state.0 * 7 + 3) % 10;
+        state.1 += state.0;
+    }
+
+    fn observe(&(digit, sum): &(u64, u64)) -> (u64, u64) {
+        (digit, sum)
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // 4, 1, 0, 3, 4
+        let cycle = find_cycle((4, 0), 100, walk, observe).unwrap();
+        assert_eq!((cycle.start, cycle.length), (0, 4));
+        assert_eq!(cycle.values, [0, 1, 1, 4, 8]);
+
+        let mut state = (4, 0);
+        for step in 0..50 {
+            assert_eq!(cycle.value_at(step), state.1, "step {}", step);
+            walk(&mut state);
+        }
+        assert_eq!(cycle.value_at(1_000_000_000_000), 2_000_000_000_000);
+
+        // 2, 7, 2
+        let cycle = find_cycle((2, 0), 100, walk, observe).unwrap();
+        assert_eq!((cycle.start, cycle.length), (0, 2));
+    }
+
+    #[test]
+    fn test_lead_in() {
+        // keyed by the digit alone, 10 and 0 are the same state: 10, 3, 4, 1, 0, 3.
+        let mut state = (10, 0);
+        let cycle = find_cycle(state, 100, walk, observe).unwrap();
+        assert_eq!((cycle.start, cycle.length), (1, 4));
+        for step in 0..50 {
+            assert_eq!(cycle.value_at(step), state.1, "step {}", step);
+            walk(&mut state);
+        }
+    }
+
+    #[test]
+    fn test_no_cycle() {
+        assert_eq!(find_cycle(0u64, 10, |n| *n += 1, |&n| (n, n)), None);
+    }
+}
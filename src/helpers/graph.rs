@@ -0,0 +1,260 @@
+use super::search;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// the index of a node in a `Graph`, in the order the nodes were added.
+pub type NodeId = usize;
+
+/// a directed graph with string labels and weighted adjacency lists, e.g. the valves and tunnels of 2022/16.
+///
+/// ```
+/// use advent_of_code::helpers::Graph;
+///
+/// let mut graph = Graph::new();
+/// for (from, to) in [("AA", "BB"), ("BB", "CC"), ("CC", "DD"), ("AA", "DD")] {
+///     graph.connect(from, to, 1);
+/// }
+/// // keep the valves that matter, with the length of the shortest tunnel between each pair.
+/// let valves = graph.contract(&["AA", "CC"]);
+/// let (aa, cc) = (valves.id("AA").unwrap(), valves.id("CC").unwrap());
+/// assert_eq!(valves.neighbours(aa), [(cc, 2)]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    labels: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<Vec<(NodeId, usize)>>,
+}
+
+/// the cost of the shortest path between every pair of nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Distances {
+    len: usize,
+    costs: Vec<Option<usize>>,
+}
+
+impl Distances {
+    fn new(len: usize) -> Self {
+        let mut costs = vec![None; len * len];
+        for node in 0..len {
+            costs[node * len + node] = Some(0);
+        }
+        Distances { len, costs }
+    }
+
+    /// the cost from `from` to `to`, `None` if `to` is unreachable.
+    pub fn get(&self, from: NodeId, to: NodeId) -> Option<usize> {
+        self.costs[from * self.len + to]
+    }
+
+    fn set(&mut self, from: NodeId, to: NodeId, cost: usize) {
+        self.costs[from * self.len + to] = Some(cost);
+    }
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Graph::default()
+    }
+
+    /// the id of the node labelled `label`, adding the node if it does not exist yet.
+    pub fn node(&mut self, label: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(label) {
+            return id;
+        }
+        let id = self.labels.len();
+        self.labels.push(label.to_string());
+        self.ids.insert(label.to_string(), id);
+        self.edges.push(vec![]);
+        id
+    }
+
+    pub fn id(&self, label: &str) -> Option<NodeId> {
+        self.ids.get(label).copied()
+    }
+
+    pub fn label(&self, id: NodeId) -> &str {
+        &self.labels[id]
+    }
+
+    /// the number of nodes.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.len()
+    }
+
+    /// adds an edge from `from` to `to`, and the nodes if they do not exist yet.
+    pub fn add_edge(&mut self, from: &str, to: &str, weight: usize) {
+        let (from, to) = (self.node(from), self.node(to));
+        self.edges[from].push((to, weight));
+    }
+
+    /// adds an edge in both directions.
+    pub fn connect(&mut self, a: &str, b: &str, weight: usize) {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+
+    /// the `(neighbour, weight)` pairs of the edges leaving `id`.
+    pub fn neighbours(&self, id: NodeId) -> &[(NodeId, usize)] {
+        &self.edges[id]
+    }
+
+    /// all-pairs shortest paths with floyd-warshall, in O(n³). fine for up to a few hundred nodes.
+    pub fn floyd_warshall(&self) -> Distances {
+        let n = self.len();
+        let mut distances = Distances::new(n);
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, weight) in edges {
+                if distances.get(from, to).is_none_or(|known| weight < known) {
+                    distances.set(from, to, weight);
+                }
+            }
+        }
+        for via in 0..n {
+            for from in 0..n {
+                let Some(first) = distances.get(from, via) else {
+                    continue;
+                };
+                for to in 0..n {
+                    if let Some(second) = distances.get(via, to) {
+                        let cost = first + second;
+                        if distances.get(from, to).is_none_or(|known| cost < known) {
+                            distances.set(from, to, cost);
+                        }
+                    }
+                }
+            }
+        }
+        distances
+    }
+
+    /// all-pairs shortest paths with a breadth-first search from every node, in O(n * e).
+    /// counts steps, so every edge costs 1 whatever its weight.
+    pub fn bfs_distances(&self) -> Distances {
+        let mut distances = Distances::new(self.len());
+        for from in self.nodes() {
+            let steps = search::bfs(from, |&node| self.edges[node].iter().map(|&(to, _)| to));
+            for (to, cost) in steps.costs {
+                distances.set(from, to, cost);
+            }
+        }
+        distances
+    }
+
+    /// a graph of only the nodes labelled in `keep`, in that order, with an edge between each pair
+    /// weighted by the cheapest path between them in this graph. unknown labels are skipped.
+    pub fn contract(&self, keep: &[&str]) -> Graph {
+        let kept: Vec<NodeId> = keep.iter().filter_map(|label| self.id(label)).collect();
+        let mut contracted = Graph::new();
+        for &node in &kept {
+            contracted.node(self.label(node));
+        }
+        for &from in &kept {
+            let paths = search::dijkstra(from, |&node| self.edges[node].iter().copied());
+            for &to in &kept {
+                if let (true, Some(cost)) = (from != to, paths.cost(&to)) {
+                    contracted.add_edge(self.label(from), self.label(to), cost);
+                }
+            }
+        }
+        contracted
+    }
+
+    /// the graph in graphviz DOT, e.g. for `dot -Tsvg`. weights other than 1 become edge labels.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for label in &self.labels {
+            writeln!(dot, "    {:?};", label).unwrap();
+        }
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, weight) in edges {
+                write!(dot, "    {:?} -> {:?}", self.labels[from], self.labels[to]).unwrap();
+                if weight != 1 {
+                    write!(dot, " [label={}]", weight).unwrap();
+                }
+                dot.push_str(";\n");
+            }
+        }
+        dot.push('}');
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a ring of tunnels AA-BB-CC-DD-EE-AA with a dead end from CC to FF and a lone GG.
+    fn tunnels() -> Graph {
+        let mut graph = Graph::new();
+        for (a, b) in [
+            ("AA", "BB"),
+            ("BB", "CC"),
+            ("CC", "DD"),
+            ("DD", "EE"),
+            ("EE", "AA"),
+            ("CC", "FF"),
+        ] {
+            graph.connect(a, b, 1);
+        }
+        graph.node("GG");
+        graph
+    }
+
+    #[test]
+    fn test_nodes() {
+        let mut graph = tunnels();
+        assert_eq!(graph.len(), 7);
+        assert_eq!(graph.node("AA"), 0);
+        assert_eq!(graph.id("FF"), Some(5));
+        assert_eq!(graph.label(6), "GG");
+        assert_eq!(graph.neighbours(0), [(1, 1), (4, 1)]);
+        assert!(graph.neighbours(6).is_empty());
+    }
+
+    #[test]
+    fn test_distances() {
+        let graph = tunnels();
+        let bfs = graph.bfs_distances();
+        let (aa, ff, gg) = (0, 5, 6);
+        assert_eq!(bfs.get(aa, ff), Some(3));
+        assert_eq!(bfs.get(ff, aa), Some(3));
+        assert_eq!(bfs.get(aa, aa), Some(0));
+        assert_eq!(bfs.get(aa, gg), None);
+        assert_eq!(graph.floyd_warshall(), bfs);
+
+        // a shortcut that is longer in steps but cheaper in weight.
+        let mut weighted = graph.clone();
+        weighted.add_edge("AA", "FF", 5);
+        weighted.add_edge("AA", "DD", 1);
+        let floyd = weighted.floyd_warshall();
+        assert_eq!(floyd.get(aa, ff), Some(3));
+        weighted.add_edge("AA", "FF", 2);
+        assert_eq!(weighted.floyd_warshall().get(aa, ff), Some(2));
+        assert_eq!(weighted.bfs_distances().get(aa, ff), Some(1));
+    }
+
+    #[test]
+    fn test_contract() {
+        let graph = tunnels();
+        let valves = graph.contract(&["AA", "FF", "GG", "ZZ"]);
+        assert_eq!(valves.len(), 3);
+        let (aa, ff, gg) = (0, 1, 2);
+        assert_eq!(valves.label(ff), "FF");
+        assert_eq!(valves.neighbours(aa), [(ff, 3)]);
+        assert_eq!(valves.neighbours(ff), [(aa, 3)]);
+        assert!(valves.neighbours(gg).is_empty());
+        assert_eq!(
+            valves.to_dot(),
+            "digraph {\n    \"AA\";\n    \"FF\";\n    \"GG\";\n    \"AA\" -> \"FF\" [label=3];\n    \"FF\" -> \"AA\" [label=3];\n}"
+        );
+    }
+}
@@ -0,0 +1,348 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// a position in a grid as `(x, y)`, i.e. `(column, row)`, with `(0, 0)` in the top left.
+pub type Pos = (usize, usize);
+
+/// offsets of the 4 orthogonal neighbours: up, right, down, left.
+pub const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// offsets of the 8 orthogonal and diagonal neighbours, clockwise from up.
+pub const OFFSETS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// a dense 2D grid, stored row-major in a single `Vec`.
+///
+/// ```
+/// use advent_of_code::helpers::Grid;
+///
+/// let grid = Grid::parse("30373\n25512", |c| c.to_digit(10)).unwrap();
+/// assert_eq!(grid.get((3, 1)), Some(&1));
+/// assert_eq!(grid.to_string(), "30373\n25512");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// a grid of `width` columns from row-major `cells`. panics if `cells` does not fill whole rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of width {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// parses a character map, one row per line, converting every character with `cell`.
+    /// fails on characters `cell` rejects and on rows of different lengths.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, String> {
+        let mut width = None;
+        let mut cells = vec![];
+        for (y, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                let value =
+                    cell(c).ok_or_else(|| format!("unexpected {:?} at ({}, {})", c, x, y))?;
+                cells.push(value);
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(format!(
+                        "row {} has {} cells, expected {}",
+                        y, row_width, width
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+
+        let width = width.unwrap_or(0);
+        Ok(Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// replaces the cell at `pos`, returning the previous value. `None` if `pos` is out of bounds.
+    pub fn set(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// `pos` moved by `offset`, if that is still inside the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// the orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_4
+            .iter()
+            .filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    /// the orthogonal and diagonal neighbours of `pos` inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_8
+            .iter()
+            .filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    /// the positions from `pos` (exclusive) in `direction` up to the edge of the grid.
+    pub fn ray(&self, pos: Pos, direction: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.offset(pos, direction), move |&pos| {
+            self.offset(pos, direction)
+        })
+    }
+
+    /// all positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// all cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// the position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks_exact` panics on a width of 0, which an empty grid has.
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// panics if `x` is outside the grid, like `row` does for `y`.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} is outside of a {}x{} grid",
+            x,
+            self.width,
+            self.height
+        );
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width)
+            .take(self.height)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// the grid mirrored along its main diagonal: rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// the grid rotated by 90° clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(x, y)| (y, self.height - 1 - x))
+    }
+
+    /// the grid rotated by 90° counter-clockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(x, y)| (self.width - 1 - y, x))
+    }
+
+    /// a `width` x `height` grid whose cell at `pos` is the cell of this grid at `source(pos)`.
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..width * height)
+            .map(|i| self[source((i % width, i / width))].clone())
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside of a {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", pos, width, height))
+    }
+}
+
+/// renders the cells of each row next to each other, rows separated by newlines.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("abc\ndef", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(
+            Grid::parse("12\n3x", |c| c.to_digit(10)),
+            Err("unexpected 'x' at (1, 1)".into())
+        );
+        assert_eq!(
+            Grid::parse("12\n345", Some),
+            Err("row 1 has 3 cells, expected 2".into())
+        );
+        assert_eq!(Grid::parse("", Some).unwrap().positions().count(), 0);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(
+            grid.ray((0, 1), (1, 0)).collect::<Vec<_>>(),
+            [(1, 1), (2, 1)]
+        );
+        assert_eq!(grid.ray((0, 1), (0, 1)).count(), 0);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid = example();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+        assert_eq!(grid.set((1, 1), 'x'), Some('e'));
+        assert_eq!(grid.set((1, 2), 'x'), None);
+        assert_eq!(grid.to_string(), "abc\ndxf");
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside of a 3x2 grid")]
+    fn test_column_outside() {
+        let _ = example().column(3);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = example();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
@@ -0,0 +1,263 @@
+use super::math::{self, Checked};
+use super::Num;
+use std::fmt::{self, Display};
+
+/// the integers from `start` to `end`, both included, e.g. the `2-4` of a section assignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Num> Interval<T> {
+    /// panics if `start > end`, as an interval cannot be empty.
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "an interval cannot end before it starts");
+        Interval { start, end }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// whether every integer of `other` is also in this interval.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.overlaps(other)
+            .then(|| Interval::new(self.start.max(other.start), self.end.min(other.end)))
+    }
+
+    /// whether both intervals form a single interval when joined, i.e. they overlap or are adjacent.
+    fn joins(&self, other: &Self) -> bool {
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        // `second.start - 1` cannot underflow, as `second.start` is above `first.end`.
+        second.start <= first.end || second.start - T::ONE == first.end
+    }
+}
+
+impl<T: Checked> Interval<T> {
+    /// the number of integers in the interval.
+    /// panics if that does not fit `T`, e.g. for `0-255` as `u8` or `-100-100` as `i8`.
+    pub fn len(&self) -> T {
+        math::sub(self.end, self.start, "interval length")
+            .and_then(|len| math::add(len, T::ONE, "interval length"))
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// a set of integers stored as sorted, disjoint and non-adjacent intervals.
+/// building a set from `n` intervals takes O(n log n), regardless of how many integers they cover.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Num> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+
+    /// the intervals of the set, in ascending order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.end < value);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        *self = self.union(&IntervalSet {
+            intervals: vec![interval],
+        });
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            if let Some(both) = a.intersection(b) {
+                intervals.push(both);
+            }
+            // the interval ending first cannot overlap anything further along the other set.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// the integers of this set that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let mut j = 0;
+        for interval in &self.intervals {
+            let mut rest = Some(*interval);
+            // skip the intervals of `other` that end before this one starts.
+            while other
+                .intervals
+                .get(j)
+                .is_some_and(|b| b.end < interval.start)
+            {
+                j += 1;
+            }
+            let mut k = j;
+            while let (Some(current), Some(b)) = (rest, other.intervals.get(k)) {
+                if b.start > current.end {
+                    break;
+                }
+                if b.start > current.start {
+                    intervals.push(Interval::new(current.start, b.start - T::ONE));
+                }
+                rest = (b.end < current.end).then(|| Interval::new(b.end + T::ONE, current.end));
+                k += 1;
+            }
+            intervals.extend(rest);
+        }
+        IntervalSet { intervals }
+    }
+
+    /// the parts of `bounds` that are not in the set.
+    pub fn gaps(&self, bounds: Interval<T>) -> Self {
+        IntervalSet {
+            intervals: vec![bounds],
+        }
+        .difference(self)
+    }
+}
+
+impl<T: Checked> IntervalSet<T> {
+    /// the number of integers in the set. panics if that does not fit `T`.
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::ZERO, |len, interval| {
+            math::add(len, interval.len(), "set length").unwrap_or_else(|e| panic!("{}", e))
+        })
+    }
+}
+
+impl<T: Num> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut sorted: Vec<Interval<T>> = iter.into_iter().collect();
+        sorted.sort_unstable();
+
+        let mut intervals: Vec<Interval<T>> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if last.joins(&interval) => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::new(2u32, 8);
+        let b = Interval::new(3, 7);
+        assert_eq!(a.len(), 7);
+        assert!(a.contains_interval(&b) && !b.contains_interval(&a));
+        assert!(a.overlaps(&Interval::new(8, 9)));
+        assert!(!a.overlaps(&Interval::new(9, 9)));
+        assert_eq!(
+            a.intersection(&Interval::new(6, 12)),
+            Some(Interval::new(6, 8))
+        );
+        assert_eq!(a.intersection(&Interval::new(0, 1)), None);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let merged = set(&[(12, 12), (-2, 2), (2, 14), (16, 24), (15, 15), (30, 31)]);
+        assert_eq!(merged, set(&[(-2, 24), (30, 31)]));
+        assert_eq!(merged.len(), 29);
+        assert!(merged.contains(24) && !merged.contains(25) && merged.contains(-2));
+
+        let mut inserted = merged.clone();
+        inserted.insert(Interval::new(25, 29));
+        assert_eq!(inserted, set(&[(-2, 31)]));
+        assert_eq!(IntervalSet::<u8>::new().len(), 0);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 22), (28, 40)]);
+        assert_eq!(a.union(&b), set(&[(0, 40)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 22), (28, 30)]));
+        assert_eq!(a.difference(&b), set(&[(0, 4), (23, 27)]));
+        assert_eq!(b.difference(&a), set(&[(11, 19), (31, 40)]));
+        assert_eq!(
+            a.difference(&set(&[(2, 3), (5, 6)])),
+            set(&[(0, 1), (4, 4), (7, 10), (20, 30)])
+        );
+        assert_eq!(a.gaps(Interval::new(-5, 25)), set(&[(-5, -1), (11, 19)]));
+        assert!(a.gaps(Interval::new(2, 8)).is_empty());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let full = Interval::new(i32::MIN, i32::MAX);
+        assert!(full.joins(&Interval::new(0, 0)));
+        assert!(!Interval::new(i32::MIN, i32::MIN).joins(&Interval::new(i32::MAX, i32::MAX)));
+        assert_eq!(
+            set(&[(i32::MIN, -1), (0, i32::MAX)]),
+            set(&[(i32::MIN, i32::MAX)])
+        );
+        assert_eq!(Interval::new(0u8, 254).len(), 255);
+        assert_eq!(set(&[(i32::MIN, i32::MAX)]).gaps(full), IntervalSet::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "interval length: 255 + 1 overflows u8")]
+    fn test_len_overflow() {
+        Interval::new(0u8, 255).len();
+    }
+}
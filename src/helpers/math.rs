@@ -0,0 +1,263 @@
+//! number theory for puzzles that wrap around: gcd and lcm, modular arithmetic and the chinese remainder theorem.
+//! functions that can overflow return an error that says what was being computed.
+//!
+//! ```
+//! use advent_of_code::helpers::math::{crt, lcm_of};
+//!
+//! // the monkeys of 2022/11 test divisibility by these, so worry levels can be kept modulo their lcm.
+//! assert_eq!(lcm_of(&[23u64, 19, 13, 17]), Ok(96577));
+//! // the first minute `t` such that buses leaving every 7, 13 and 59 minutes leave at `t`, `t + 1` and `t + 4`.
+//! assert_eq!(crt(&[(0i64, 7), (-1, 13), (-4, 59)]), Ok((350, 5369)));
+//! ```
+use super::Num;
+use std::any::type_name;
+use std::fmt::Display;
+use std::ops::Neg;
+
+/// the integer types whose arithmetic can be checked for overflow.
+pub trait Checked: Num + Display {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked {
+    ($($t:ty),*) => {
+        $(impl Checked for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        })*
+    };
+}
+
+impl_checked!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+fn overflow<T: Display>(context: &str, a: T, operator: char, b: T) -> String {
+    format!(
+        "{}: {} {} {} overflows {}",
+        context,
+        a,
+        operator,
+        b,
+        type_name::<T>()
+    )
+}
+
+/// `a + b`, or an error such as `worry level: 200 + 100 overflows u8`.
+pub fn add<T: Checked>(a: T, b: T, context: &str) -> Result<T, String> {
+    a.checked_add(b).ok_or_else(|| overflow(context, a, '+', b))
+}
+
+/// `a - b`, or an error such as `stock: 1 - 2 overflows u32`.
+pub fn sub<T: Checked>(a: T, b: T, context: &str) -> Result<T, String> {
+    a.checked_sub(b).ok_or_else(|| overflow(context, a, '-', b))
+}
+
+/// `a * b`, or an error such as `worry level: 300 * 300 overflows u16`.
+pub fn mul<T: Checked>(a: T, b: T, context: &str) -> Result<T, String> {
+    a.checked_mul(b).ok_or_else(|| overflow(context, a, '*', b))
+}
+
+fn abs<T: Num>(a: T) -> T {
+    if a < T::ZERO {
+        T::ZERO - a
+    } else {
+        a
+    }
+}
+
+/// the remainder of `a / m` in `0..m`, also for negative `a`.
+pub fn modulo<T: Num>(a: T, m: T) -> T {
+    let r = a % m;
+    if r < T::ZERO {
+        r + m
+    } else {
+        r
+    }
+}
+
+/// the greatest common divisor, never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Num>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    abs(a)
+}
+
+/// the greatest common divisor of all `values`, 0 for none.
+pub fn gcd_of<T: Num>(values: &[T]) -> T {
+    values.iter().fold(T::ZERO, |g, &value| gcd(g, value))
+}
+
+/// the least common multiple, never negative. 0 if either is 0.
+pub fn lcm<T: Checked>(a: T, b: T) -> Result<T, String> {
+    if a == T::ZERO || b == T::ZERO {
+        return Ok(T::ZERO);
+    }
+    let (a, b) = (abs(a), abs(b));
+    mul(a / gcd(a, b), b, "lcm")
+}
+
+/// the least common multiple of all `values`, 1 for none.
+pub fn lcm_of<T: Checked>(values: &[T]) -> Result<T, String> {
+    values.iter().try_fold(T::ONE, |l, &value| lcm(l, value))
+}
+
+/// `(g, x, y)` such that `a * x + b * y == g`, where `g` is the gcd of `a` and `b`.
+pub fn extended_gcd<T: Num + Neg<Output = T>>(a: T, b: T) -> (T, T, T) {
+    // invariant: a * x0 + b * y0 == r0 and a * x1 + b * y1 == r1.
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::ONE, T::ZERO);
+    let (mut y0, mut y1) = (T::ZERO, T::ONE);
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < T::ZERO {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// `x` in `0..m` such that `a * x` is 1 modulo `m`, `None` if `a` and `m` are not coprime.
+pub fn mod_inverse<T: Num + Neg<Output = T>>(a: T, m: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(modulo(a, m), m);
+    (g == T::ONE).then(|| modulo(x, m))
+}
+
+/// `a * b` modulo `m`, even if `a * b` overflows. `m + m` must fit in `T`.
+pub fn mul_mod<T: Checked>(a: T, b: T, m: T) -> T {
+    let (mut a, mut b) = (modulo(a, m), modulo(b, m));
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+    // double and add, so no intermediate value exceeds `2 * m`.
+    let two = T::ONE + T::ONE;
+    let mut product = T::ZERO;
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            product = (product + a) % m;
+        }
+        a = (a + a) % m;
+        b = b / two;
+    }
+    product
+}
+
+/// `base` to the power of `exp` modulo `m`, in O(log exp) multiplications. `m + m` must fit in `T`.
+pub fn modpow<T: Checked>(base: T, exp: T, m: T) -> T {
+    let two = T::ONE + T::ONE;
+    let (mut base, mut exp) = (modulo(base, m), exp);
+    let mut result = modulo(T::ONE, m);
+    while exp > T::ZERO {
+        if exp % two == T::ONE {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp = exp / two;
+    }
+    result
+}
+
+/// the chinese remainder theorem: the `x` in `0..lcm` that is `residue` modulo `modulus` for every
+/// `(residue, modulus)` pair, and the lcm of the moduli. every `x + k * lcm` is a solution as well.
+/// moduli do not have to be coprime, but then the congruences may contradict each other.
+pub fn crt<T: Checked + Neg<Output = T>>(congruences: &[(T, T)]) -> Result<(T, T), String> {
+    let (mut x, mut m) = (T::ZERO, T::ONE);
+    for &(residue, modulus) in congruences {
+        let residue = modulo(residue, modulus);
+        let g = gcd(m, modulus);
+        let diff = residue - x;
+        if diff % g != T::ZERO {
+            return Err(format!(
+                "x = {} (mod {}) contradicts x = {} (mod {})",
+                residue, modulus, x, m
+            ));
+        }
+        // solve x + m * k = residue (mod modulus) for k.
+        let step = modulus / g;
+        let k = mul_mod(diff / g, mod_inverse(m / g, step).unwrap(), step);
+        let lcm = mul(m, step, "crt modulus")?;
+        x = modulo(x + m * k, lcm);
+        m = lcm;
+    }
+    Ok((x, m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0u32, 0), 0);
+        assert_eq!(gcd_of(&[12, 18, 27]), 3);
+        assert_eq!(lcm(4, 6), Ok(12));
+        assert_eq!(lcm(0, 6), Ok(0));
+        assert_eq!(lcm_of::<u32>(&[]), Ok(1));
+        assert_eq!(lcm_of(&[2u64, 3, 4, 5, 6]), Ok(60));
+        assert_eq!(lcm_of(&[250u8, 3]), Err("lcm: 250 * 3 overflows u8".into()));
+    }
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(add(200u8, 55, "sum"), Ok(255));
+        assert_eq!(
+            add(200u8, 100, "worry level"),
+            Err("worry level: 200 + 100 overflows u8".into())
+        );
+        assert_eq!(
+            sub(1u32, 2, "stock"),
+            Err("stock: 1 - 2 overflows u32".into())
+        );
+        assert_eq!(mul(-3i8, 40, "score"), Ok(-120));
+        assert!(mul(i64::MAX, 2, "score").is_err());
+    }
+
+    #[test]
+    fn test_modular() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(extended_gcd(-4, 6).0, 2);
+
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 6), None);
+
+        assert_eq!(modulo(-7, 5), 3);
+        assert_eq!(modpow(4u32, 13, 497), 445);
+        assert_eq!(modpow(5u32, 0, 1), 0);
+        // the product overflows u64, the result does not.
+        let m = (1u64 << 62) + 57;
+        assert_eq!(mul_mod(m - 1, m - 1, m), 1);
+        assert_eq!(modpow(m - 1, 3, m), m - 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        assert_eq!(crt(&[(1i64, 4), (3, 6)]), Ok((9, 12)));
+        assert_eq!(
+            crt(&[(2, 4), (1, 6)]),
+            Err("x = 1 (mod 6) contradicts x = 2 (mod 4)".into())
+        );
+        assert_eq!(crt::<i32>(&[]), Ok((0, 1)));
+        assert!(crt(&[(0i8, 11), (0, 13)]).is_err());
+    }
+}
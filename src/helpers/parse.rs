@@ -0,0 +1,192 @@
+//! nom combinators for the usual shapes of puzzle inputs, and `finish` to run them with readable errors.
+//!
+//! ```
+//! use advent_of_code::helpers::parse::{finish, int, key_value, lines_of};
+//!
+//! let input = "Alice: 3\nBob: -12\n";
+//! let scores = finish(input, lines_of(key_value(int::<i32>))).unwrap();
+//! assert_eq!(scores, [("Alice", 3), ("Bob", -12)]);
+//! ```
+use super::Grid;
+use nom::{
+    bytes::complete::{tag, take_till1},
+    character::complete::{digit1, line_ending, not_line_ending, one_of, space0},
+    combinator::{map_res, opt, recognize},
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::{pair, separated_pair, tuple},
+    Finish, IResult,
+};
+use std::str::FromStr;
+
+/// an integer with an optional sign, e.g. `-12`, as any integer type.
+pub fn int<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// an integer without a sign, e.g. `12`, as any integer type.
+pub fn uint<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// every integer in `text`, e.g. `[2, 18, -2]` for `Sensor at x=2, y=18: beacon at x=-2`.
+/// a `-` only counts as a sign if it does not follow a digit, so `2-4` is `[2, 4]`.
+/// numbers that do not fit `T` are skipped rather than changed, e.g. `ints::<u32>` leaves out `-3`.
+pub fn ints<T: FromStr>(text: &str) -> Vec<T> {
+    let bytes = text.as_bytes();
+    let mut numbers = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let negative =
+            bytes[i] == b'-' && (i == 0 || !bytes[i - 1].is_ascii_digit()) && i + 1 < bytes.len();
+        let start = if negative { i + 1 } else { i };
+        if !bytes[start].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let end = start
+            + bytes[start..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+        // a number that does not fit `T`, e.g. a negative one for an unsigned type, is skipped.
+        if let Ok(number) = text[i..end].parse() {
+            numbers.push(number);
+        }
+        i = end;
+    }
+    numbers
+}
+
+/// `parser` applied to each line, e.g. `lines_of(int::<u32>)`.
+pub fn lines_of<'a, T>(
+    parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(line_ending, parser)
+}
+
+/// `parser` applied to each block of lines, with blocks separated by a blank line.
+pub fn blocks_of<'a, T>(
+    parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(pair(line_ending, line_ending), parser)
+}
+
+/// a map of characters up to a blank line or the end of the input, converted with `cell`.
+pub fn char_grid<'a, T>(
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    move |input| {
+        let (rest, rows) = recognize(separated_list1(
+            line_ending,
+            take_till1(|c| c == '\n' || c == '\r'),
+        ))(input)?;
+        let grid = Grid::parse(rows, &mut cell)
+            .map_err(|_| nom::Err::Error(Error::new(input, ErrorKind::MapRes)))?;
+        Ok((rest, grid))
+    }
+}
+
+/// a `key: value` line such as `Starting items: 79, 98`, with the value read by `value`.
+pub fn key_value<'a, T>(
+    value: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, T)> {
+    let key = |input: &'a str| {
+        let (rest, key) = take_till1(|c| c == ':' || c == '\n')(input)?;
+        Ok((rest, key.trim()))
+    };
+    separated_pair(key, tuple((tag(":"), space0)), value)
+}
+
+/// the rest of the line, e.g. as the value of `key_value(rest_of_line)`.
+pub fn rest_of_line(input: &str) -> IResult<&str, &str> {
+    not_line_ending(input)
+}
+
+/// runs `parser` on the whole `input`. only trailing whitespace may remain.
+/// errors name the line and column, e.g. `line 2, column 5: expected Digit near "x, 3"`.
+pub fn finish<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, String> {
+    let describe = |rest: &str, expected: String| {
+        let offset = input.len() - rest.len();
+        let line = input[..offset].matches('\n').count() + 1;
+        let column = offset - input[..offset].rfind('\n').map_or(0, |i| i + 1) + 1;
+        let near: String = rest
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .take(20)
+            .collect();
+        format!(
+            "line {}, column {}: {} near {:?}",
+            line, column, expected, near
+        )
+    };
+
+    match parser(input).finish() {
+        // lists stop before the separator of the element that failed, point at the element instead.
+        Ok((rest, _)) if !rest.trim().is_empty() => Err(describe(
+            rest.trim_start_matches(['\r', '\n']),
+            "unexpected input".into(),
+        )),
+        Ok((_, value)) => Ok(value),
+        Err(e) => Err(describe(e.input, format!("expected {:?}", e.code))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(int::<i64>("-12 apples"), Ok((" apples", -12)));
+        assert_eq!(uint::<u8>("42"), Ok(("", 42)));
+        assert!(uint::<u8>("-1").is_err());
+        assert!(int::<u8>("300").is_err());
+
+        assert_eq!(
+            ints::<i32>("Sensor at x=2, y=18: closest beacon is at x=-2, y=15"),
+            [2, 18, -2, 15]
+        );
+        assert_eq!(ints::<u32>("2-4,6-8"), [2, 4, 6, 8]);
+        assert_eq!(ints::<u32>("move -3 to 1-"), [1]);
+        assert_eq!(ints::<u8>("1 300 -0 2"), [1, 2]);
+        assert_eq!(ints::<i32>("no numbers -"), []);
+    }
+
+    #[test]
+    fn test_shapes() {
+        let input = "#.\n.#\n\n##\n..";
+        let (_, grids) = blocks_of(char_grid(Some))(input).unwrap();
+        assert_eq!(grids.len(), 2);
+        assert_eq!(grids[1].to_string(), "##\n..");
+
+        let (_, items) = key_value(rest_of_line)("  Starting items: 79, 98").unwrap();
+        assert_eq!(items, ("Starting items", "79, 98"));
+
+        let (_, blocks) = blocks_of(lines_of(uint::<u32>))("1\n2\n\n3\r\n\r\n4").unwrap();
+        assert_eq!(blocks, [vec![1, 2], vec![3], vec![4]]);
+    }
+
+    #[test]
+    fn test_finish() {
+        assert_eq!(finish("1\n2\n", lines_of(uint::<u32>)), Ok(vec![1, 2]));
+        assert_eq!(
+            finish("1\n2\nx3", lines_of(uint::<u32>)),
+            Err("line 3, column 1: unexpected input near \"x3\"".into())
+        );
+        assert_eq!(
+            finish("a: 1\nb: x", lines_of(key_value(int::<i32>))),
+            Err("line 2, column 1: unexpected input near \"b: x\"".into())
+        );
+        assert_eq!(
+            finish("a: x", key_value(int::<i32>)),
+            Err("line 1, column 4: expected Digit near \"x\"".into())
+        );
+    }
+}
@@ -0,0 +1,462 @@
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
+use std::str::FromStr;
+
+/// the integer types points and vectors are made of.
+pub trait Num:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_num {
+    ($($t:ty),*) => {
+        $(impl Num for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        })*
+    };
+}
+
+impl_num!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+fn abs_diff<T: Num>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// a position on a 2D plane. as in `Grid`, `y` grows downwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// the offset between two `Point2`s.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Num> Point2<T> {
+    pub const ORIGIN: Self = Point2 {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// the number of king moves between two points, i.e. the larger of both axis distances.
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// whether the points are the same or neighbours, diagonals included.
+    pub fn touches(self, other: Self) -> bool {
+        self.chebyshev(other) <= T::ONE
+    }
+}
+
+impl<T: Num + Neg<Output = T>> Point2<T> {
+    /// moves one step towards `target` on both axes, unless the points already touch.
+    /// this is how the knots of a rope follow each other (2022/09).
+    pub fn follow(self, target: Self) -> Self {
+        if self.touches(target) {
+            self
+        } else {
+            self + (target - self).signum()
+        }
+    }
+}
+
+impl<T: Num> Vec2<T> {
+    pub const ZERO: Self = Vec2 {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
+    pub const fn new(x: T, y: T) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// clamps both components to `min..=max`.
+    pub fn clamp(self, min: T, max: T) -> Self {
+        Vec2::new(self.x.clamp(min, max), self.y.clamp(min, max))
+    }
+}
+
+impl<T: Num + Neg<Output = T>> Vec2<T> {
+    /// `-1`, `0` or `1` for each component.
+    pub fn signum(self) -> Self {
+        self.clamp(-T::ONE, T::ONE)
+    }
+}
+
+impl<T: Num> Add<Vec2<T>> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, rhs: Vec2<T>) -> Self::Output {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Num> AddAssign<Vec2<T>> for Point2<T> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Num> Sub<Vec2<T>> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, rhs: Vec2<T>) -> Self::Output {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Num> SubAssign<Vec2<T>> for Point2<T> {
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Num> Sub for Point2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Num> Add for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Num> Sub for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Num> Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Num + Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn neg(self) -> Self::Output {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// grid positions are `(x, y)` tuples.
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+/// a position in 3D space, e.g. a cube of lava (2022/18).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Num> Point3<T> {
+    pub const ORIGIN: Self = Point3 {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+    };
+
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    /// the smallest coordinates of both points on each axis.
+    pub fn min(self, other: Self) -> Self {
+        Point3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// the largest coordinates of both points on each axis.
+    pub fn max(self, other: Self) -> Self {
+        Point3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    /// the lowest and highest corner of the smallest box containing all `points`, `None` if there are none.
+    pub fn bounding_box(points: impl IntoIterator<Item = Self>) -> Option<(Self, Self)> {
+        points.into_iter().fold(None, |bounds, point| match bounds {
+            None => Some((point, point)),
+            Some((min, max)) => Some((point.min(min), point.max(max))),
+        })
+    }
+}
+
+impl<T: Num + Neg<Output = T>> Point3<T> {
+    /// the points sharing a face with this one.
+    pub fn neighbours6(self) -> [Self; 6] {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            (-one, zero, zero),
+            (one, zero, zero),
+            (zero, -one, zero),
+            (zero, one, zero),
+            (zero, zero, -one),
+            (zero, zero, one),
+        ]
+        .map(|(dx, dy, dz)| Point3::new(self.x + dx, self.y + dy, self.z + dz))
+    }
+
+    /// the points sharing a face, an edge or a corner with this one.
+    pub fn neighbours26(self) -> impl Iterator<Item = Self> {
+        let steps = [-T::ONE, T::ZERO, T::ONE];
+        steps
+            .into_iter()
+            .flat_map(move |dx| steps.into_iter().map(move |dy| (dx, dy)))
+            .flat_map(move |(dx, dy)| steps.into_iter().map(move |dz| (dx, dy, dz)))
+            .filter(|&offset| offset != (T::ZERO, T::ZERO, T::ZERO))
+            .map(move |(dx, dy, dz)| Point3::new(self.x + dx, self.y + dy, self.z + dz))
+    }
+}
+
+impl<T: Num> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Num> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+/// parses `x,y,z`, e.g. `2,-1,5`.
+impl<T: FromStr> FromStr for Point3<T> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("\"{}\" is not a point x,y,z", s);
+        let mut coordinates = s
+            .split(',')
+            .map(|c| c.trim().parse().map_err(|_| invalid()));
+        match (
+            coordinates.next(),
+            coordinates.next(),
+            coordinates.next(),
+            coordinates.next(),
+        ) {
+            (Some(x), Some(y), Some(z), None) => Ok(Point3 {
+                x: x?,
+                y: y?,
+                z: z?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// one of the four orthogonal directions. `Up` points to smaller `y`, as in `Grid`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// all directions, clockwise from `Up`, in the same order as `OFFSETS_4`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// the step in this direction, e.g. `(0, -1)` for `Up`. works with `Grid::offset` and `Grid::ray`.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    pub fn vec<T: Num + Neg<Output = T>>(self) -> Vec2<T> {
+        match self {
+            Direction::Up => Vec2::new(T::ZERO, -T::ONE),
+            Direction::Right => Vec2::new(T::ONE, T::ZERO),
+            Direction::Down => Vec2::new(T::ZERO, T::ONE),
+            Direction::Left => Vec2::new(-T::ONE, T::ZERO),
+        }
+    }
+}
+
+/// accepts `U/R/D/L`, arrows `^>v<` and compass points `N/E/S/W`.
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | '^' | 'N' => Ok(Direction::Up),
+            'R' | '>' | 'E' => Ok(Direction::Right),
+            'D' | 'v' | 'S' => Ok(Direction::Down),
+            'L' | '<' | 'W' => Ok(Direction::Left),
+            c => Err(format!("{:?} is not a direction", c)),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(format!("\"{}\" is not a direction", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point2::new(1, 5);
+        let b = Point2::new(4, 1);
+        assert_eq!(b - a, Vec2::new(3, -4));
+        assert_eq!(a + (b - a) * 2, Point2::new(7, -3));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point2::<u32>::new(1, 5).manhattan(Point2::new(4, 1)), 7);
+        assert_eq!(Vec2::new(3, -4).signum(), Vec2::new(1, -1));
+    }
+
+    #[test]
+    fn test_follow() {
+        let tail = Point2::ORIGIN;
+        assert_eq!(tail.follow(Point2::new(1, 1)), tail);
+        assert_eq!(tail.follow(Point2::new(2, 0)), Point2::new(1, 0));
+        assert_eq!(tail.follow(Point2::new(2, -1)), Point2::new(1, -1));
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!("U".parse(), Ok(Direction::Up));
+        assert_eq!(Direction::try_from('<'), Ok(Direction::Left));
+        assert_eq!(Direction::try_from('E'), Ok(Direction::Right));
+        assert!("X".parse::<Direction>().is_err());
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Down.opposite(), Direction::Up);
+        assert_eq!(Point2::new(2, 2) + Direction::Up.vec(), Point2::new(2, 1));
+    }
+
+    #[test]
+    fn test_point3() {
+        let p: Point3<i32> = "2,-1,5".parse().unwrap();
+        assert_eq!(p, Point3::new(2, -1, 5));
+        assert_eq!(p.to_string(), "2,-1,5");
+        assert!("1,2".parse::<Point3<i32>>().is_err());
+        assert!("1,2,3,4".parse::<Point3<i32>>().is_err());
+        assert!("1,x,3".parse::<Point3<i32>>().is_err());
+
+        assert_eq!(p.manhattan(Point3::ORIGIN), 8);
+        assert_eq!(p - Point3::new(1, 1, 1) + Point3::new(1, 1, 1), p);
+        assert!(p.neighbours6().iter().all(|&n| n.manhattan(p) == 1));
+        assert_eq!(p.neighbours26().count(), 26);
+        assert!(p.neighbours26().all(|n| n != p));
+        assert_eq!(
+            Point3::bounding_box([p, Point3::new(0, 3, 1), Point3::new(4, 0, 0)]),
+            Some((Point3::new(0, -1, 0), Point3::new(4, 3, 5)))
+        );
+        assert_eq!(Point3::<u8>::bounding_box([]), None);
+    }
+}
@@ -0,0 +1,120 @@
+use std::fmt::Display;
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+/// a progress line on stderr for long-running parts, e.g. `rounds: 4200/10000 (42%)`.
+/// it is only drawn when stderr is a terminal, at most every 100ms, and cleared when dropped.
+/// when stderr is not a terminal, every update is a single branch.
+///
+/// ```no_run
+/// use advent_of_code::helpers::Progress;
+///
+/// let mut progress = Progress::new("rounds").with_total(10000);
+/// for _ in 0..10000 {
+///     progress.inc();
+/// }
+/// ```
+pub struct Progress {
+    label: String,
+    total: Option<u64>,
+    current: u64,
+    status: String,
+    enabled: bool,
+    last_draw: Option<Instant>,
+}
+
+impl Progress {
+    pub fn new(label: &str) -> Self {
+        Progress {
+            label: label.to_string(),
+            total: None,
+            current: 0,
+            status: String::new(),
+            enabled: io::stderr().is_terminal(),
+            last_draw: None,
+        }
+    }
+
+    pub fn with_total(mut self, total: u64) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    pub fn inc(&mut self) {
+        self.set(self.current + 1);
+    }
+
+    pub fn set(&mut self, current: u64) {
+        self.current = current;
+        if self.due() {
+            self.draw();
+        }
+    }
+
+    /// a free-form status shown after the count. it is only formatted when the line is redrawn.
+    pub fn set_status(&mut self, status: impl Display) {
+        if self.due() {
+            self.status = status.to_string();
+            self.draw();
+        }
+    }
+
+    /// the line as it would be drawn.
+    pub fn line(&self) -> String {
+        let mut line = format!("{}: {}", self.label, self.current);
+        if let Some(total) = self.total {
+            let percent = self.current * 100 / total.max(1);
+            line.push_str(&format!("/{} ({}%)", total, percent));
+        }
+        if !self.status.is_empty() {
+            line.push_str(&format!(" {}", self.status));
+        }
+        line
+    }
+
+    fn due(&self) -> bool {
+        self.enabled
+            && self
+                .last_draw
+                .is_none_or(|last_draw| last_draw.elapsed() >= REDRAW_INTERVAL)
+    }
+
+    fn draw(&mut self) {
+        self.last_draw = Some(Instant::now());
+        let mut stderr = io::stderr();
+        let _ = write!(stderr, "\r\x1b[2K{}", self.line());
+        let _ = stderr.flush();
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.last_draw.is_some() {
+            let _ = write!(io::stderr(), "\r\x1b[2K");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line() {
+        let mut progress = Progress::new("rounds").with_total(10000);
+        progress.set(4200);
+        assert_eq!(progress.line(), "rounds: 4200/10000 (42%)");
+
+        // off a terminal, updates are not even formatted.
+        let mut progress = Progress::new("rocks");
+        progress.enabled = false;
+        progress.inc();
+        progress.set_status("height 17");
+        assert_eq!(progress.line(), "rocks: 1");
+
+        progress.status = "height 17".into();
+        assert_eq!(progress.line(), "rocks: 1 height 17");
+    }
+}
@@ -0,0 +1,324 @@
+//! graph searches over a neighbour function, so the graph never has to be built.
+//!
+//! ```
+//! use advent_of_code::helpers::search;
+//!
+//! // shortest way from 1 to 10 with the steps `+1` and `*2`.
+//! let path = search::bfs_to(1, |&n: &u32| [n + 1, n * 2], |&n| n == 10).unwrap();
+//! assert_eq!(path.nodes, [1, 2, 4, 5, 10]);
+//! assert_eq!(path.cost, 4);
+//! ```
+use super::Num;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// a path from a start to a target, both included, and its total cost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+/// the cheapest known cost of every reached node and the node it was reached from.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    pub costs: HashMap<N, C>,
+    pub predecessors: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new() -> Self {
+        Paths {
+            costs: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// the cost of reaching `node`, `None` if it is unreachable.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// the path from the start that reached `target` first, `None` if `target` is unreachable.
+    pub fn path_to(&self, target: &N) -> Option<Path<N, C>> {
+        let cost = self.cost(target)?;
+        let mut nodes = vec![target.clone()];
+        while let Some(previous) = self.predecessors.get(nodes.last().unwrap()) {
+            nodes.push(previous.clone());
+        }
+        nodes.reverse();
+        Some(Path { nodes, cost })
+    }
+}
+
+/// breadth-first search from all `starts` at once. every step costs 1.
+/// stops as soon as `goal` matches a node and returns it, otherwise explores everything reachable.
+fn breadth_first<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> (Paths<N, usize>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !paths.costs.contains_key(&start) {
+            paths.costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            return (paths, Some(node));
+        }
+        let cost = paths.costs[&node] + 1;
+        for next in neighbours(&node) {
+            if !paths.costs.contains_key(&next) {
+                paths.costs.insert(next.clone(), cost);
+                paths.predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    (paths, None)
+}
+
+/// the distance of every node reachable from `start`.
+pub fn bfs<N, I>(start: N, neighbours: impl FnMut(&N) -> I) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_multi([start], neighbours)
+}
+
+/// the distance of every reachable node to the nearest of `starts`.
+pub fn bfs_multi<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    breadth_first(starts, neighbours, |_| false).0
+}
+
+/// the shortest path from `start` to the nearest node matching `goal`.
+pub fn bfs_to<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_multi_to([start], neighbours, goal)
+}
+
+/// the shortest path from any of `starts` to the nearest node matching `goal`.
+pub fn bfs_multi_to<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let (paths, target) = breadth_first(starts, neighbours, goal);
+    paths.path_to(&target?)
+}
+
+/// an entry of the open set, ordered so the `BinaryHeap` pops the lowest estimate first.
+struct Open<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Open<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N, C: Ord> Eq for Open<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Open<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Open<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+/// dijkstra's algorithm, or A* with a `heuristic` that never overestimates the remaining cost.
+/// stops as soon as `goal` matches the cheapest open node and returns it.
+fn best_first<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> (Paths<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Num,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let mut open = BinaryHeap::new();
+    paths.costs.insert(start.clone(), C::ZERO);
+    open.push(Open {
+        estimate: heuristic(&start),
+        cost: C::ZERO,
+        node: start,
+    });
+
+    while let Some(Open { cost, node, .. }) = open.pop() {
+        // a cheaper way to `node` was found after this entry was queued.
+        if paths.costs[&node] < cost {
+            continue;
+        }
+        if goal(&node) {
+            return (paths, Some(node));
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if paths.cost(&next).is_some_and(|known| known <= next_cost) {
+                continue;
+            }
+            paths.costs.insert(next.clone(), next_cost);
+            paths.predecessors.insert(next.clone(), node.clone());
+            open.push(Open {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+
+    (paths, None)
+}
+
+/// the cheapest cost of every node reachable from `start`, given the `(neighbour, cost)` pairs of each node.
+pub fn dijkstra<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Num,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(start, neighbours, |_| C::ZERO, |_| false).0
+}
+
+/// the cheapest path from `start` to the nearest node matching `goal`.
+pub fn dijkstra_to<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Num,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (paths, target) = best_first(start, neighbours, |_| C::ZERO, goal);
+    paths.path_to(&target?)
+}
+
+/// the cheapest path from `start` to a node matching `goal`, guided by a `heuristic` that must
+/// never overestimate the remaining cost, e.g. the manhattan distance on a grid.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Num,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (paths, target) = best_first(start, neighbours, heuristic, goal);
+    paths.path_to(&target?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{Grid, Point2, Pos};
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#E#.
+.####.#.
+......#.";
+
+    fn maze() -> (Grid<char>, Pos, Pos) {
+        let grid = Grid::parse(MAZE, Some).unwrap();
+        let start = grid.position(|&c| c == 'S').unwrap();
+        let end = grid.position(|&c| c == 'E').unwrap();
+        (grid, start, end)
+    }
+
+    fn open<'a>(grid: &'a Grid<char>) -> impl FnMut(&Pos) -> Vec<Pos> + 'a {
+        |&pos| grid.neighbours4(pos).filter(|&n| grid[n] != '#').collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (grid, start, end) = maze();
+        let paths = bfs(start, open(&grid));
+        assert_eq!(paths.cost(&end), Some(11));
+        assert_eq!(paths.cost(&(7, 0)), Some(11));
+        // walled in.
+        assert_eq!(paths.cost(&(2, 0)), None);
+
+        let path = bfs_to(start, open(&grid), |&pos| pos == end).unwrap();
+        assert_eq!(path.cost, 11);
+        assert_eq!(path.nodes.len(), 12);
+        assert_eq!((path.nodes[0], path.nodes[11]), (start, end));
+        assert_eq!(path.nodes[5], (1, 4));
+        assert!(bfs_to(start, open(&grid), |&pos| pos == (2, 0)).is_none());
+
+        let paths = bfs_multi([start, (3, 4)], open(&grid));
+        assert_eq!(paths.cost(&end), Some(4));
+    }
+
+    #[test]
+    fn test_weighted() {
+        // stepping onto a wall costs 30, any other step 1.
+        let (grid, start, end) = maze();
+        let neighbours = |&pos: &Pos| {
+            grid.neighbours4(pos)
+                .map(|n| (n, if grid[n] == '#' { 30 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+
+        let paths = dijkstra(start, neighbours);
+        assert_eq!(paths.cost(&end), Some(11));
+        assert_eq!(paths.cost(&(2, 0)), Some(31));
+        assert_eq!(
+            paths.path_to(&(2, 0)).unwrap().nodes,
+            [(0, 0), (1, 0), (2, 0)]
+        );
+
+        let to_end = dijkstra_to(start, neighbours, |&pos| pos == end).unwrap();
+        let manhattan = |&pos: &Pos| Point2::from(pos).manhattan(Point2::from(end));
+        let guided = astar(start, neighbours, manhattan, |&pos| pos == end).unwrap();
+        assert_eq!(guided, to_end);
+        assert_eq!(guided.cost, 11);
+    }
+}
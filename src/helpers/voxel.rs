@@ -0,0 +1,233 @@
+use super::Point3;
+use std::ops::{Index, IndexMut};
+
+/// a position in a `VoxelGrid`.
+pub type Voxel = Point3<i32>;
+
+/// a dense 3D grid covering the box from `min` to `max`, both included, e.g. the cubes of a lava droplet.
+/// coordinates may be negative, and anything outside the box is treated as empty space.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoxelGrid<T> {
+    min: Voxel,
+    max: Voxel,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> VoxelGrid<T> {
+    /// panics if `min` is larger than `max` on any axis.
+    pub fn new(min: Voxel, max: Voxel, value: T) -> Self {
+        assert!(
+            min.x <= max.x && min.y <= max.y && min.z <= max.z,
+            "{} is not below {}",
+            min,
+            max
+        );
+        let size = max - min + Point3::new(1, 1, 1);
+        let len = size.x as usize * size.y as usize * size.z as usize;
+        VoxelGrid {
+            min,
+            max,
+            cells: vec![value; len],
+        }
+    }
+}
+
+impl<T> VoxelGrid<T> {
+    pub fn min(&self) -> Voxel {
+        self.min
+    }
+
+    pub fn max(&self) -> Voxel {
+        self.max
+    }
+
+    pub fn contains(&self, voxel: Voxel) -> bool {
+        voxel.min(self.min) == self.min && voxel.max(self.max) == self.max
+    }
+
+    fn index(&self, voxel: Voxel) -> Option<usize> {
+        if !self.contains(voxel) {
+            return None;
+        }
+        let size = self.max - self.min + Point3::new(1, 1, 1);
+        let offset = voxel - self.min;
+        Some(((offset.z * size.y + offset.y) * size.x + offset.x) as usize)
+    }
+
+    pub fn get(&self, voxel: Voxel) -> Option<&T> {
+        self.index(voxel).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, voxel: Voxel) -> Option<&mut T> {
+        self.index(voxel).map(|i| &mut self.cells[i])
+    }
+
+    /// replaces the value at `voxel` and returns the old one, `None` if `voxel` is out of bounds.
+    pub fn set(&mut self, voxel: Voxel, value: T) -> Option<T> {
+        self.get_mut(voxel)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// every position of the grid, `x` changing fastest.
+    pub fn positions(&self) -> impl Iterator<Item = Voxel> {
+        let (min, max) = (self.min, self.max);
+        (min.z..=max.z).flat_map(move |z| {
+            (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point3::new(x, y, z)))
+        })
+    }
+
+    /// every position with its value, in the same order as `positions`.
+    pub fn iter(&self) -> impl Iterator<Item = (Voxel, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// the neighbours sharing a face with `voxel` that are inside the grid.
+    pub fn neighbours6(&self, voxel: Voxel) -> impl Iterator<Item = Voxel> + '_ {
+        voxel
+            .neighbours6()
+            .into_iter()
+            .filter(|&n| self.contains(n))
+    }
+
+    /// the neighbours sharing a face, an edge or a corner with `voxel` that are inside the grid.
+    pub fn neighbours26(&self, voxel: Voxel) -> impl Iterator<Item = Voxel> + '_ {
+        voxel.neighbours26().filter(|&n| self.contains(n))
+    }
+
+    /// the number of faces between a `solid` voxel and anything else, including enclosed air pockets.
+    pub fn surface_faces(&self, solid: impl Fn(&T) -> bool) -> usize {
+        let is_solid = |voxel: Voxel| self.get(voxel).is_some_and(&solid);
+        self.positions()
+            .filter(|&voxel| is_solid(voxel))
+            .map(|voxel| {
+                voxel
+                    .neighbours6()
+                    .iter()
+                    .filter(|&&n| !is_solid(n))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// the voxels reachable from outside the bounding box through `open` voxels, as a grid one voxel
+    /// larger than this one on every side, so the outside fully wraps around the shape.
+    pub fn flood_fill_outside(&self, open: impl Fn(&T) -> bool) -> VoxelGrid<bool> {
+        let padding = Point3::new(1, 1, 1);
+        let mut reached = VoxelGrid::new(self.min - padding, self.max + padding, false);
+        let is_open = |voxel: Voxel| self.get(voxel).is_none_or(&open);
+
+        let start = reached.min;
+        let mut stack = vec![start];
+        reached[start] = true;
+        while let Some(voxel) = stack.pop() {
+            for next in voxel.neighbours6() {
+                if reached.get(next) == Some(&false) && is_open(next) {
+                    reached[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        reached
+    }
+
+    /// the number of faces between a `solid` voxel and the outside, ignoring enclosed air pockets.
+    pub fn exterior_faces(&self, solid: impl Fn(&T) -> bool) -> usize {
+        let outside = self.flood_fill_outside(|cell| !solid(cell));
+        self.iter()
+            .filter(|(_, cell)| solid(cell))
+            .map(|(voxel, _)| voxel.neighbours6().iter().filter(|&&n| outside[n]).count())
+            .sum()
+    }
+}
+
+impl VoxelGrid<bool> {
+    /// a grid just large enough for `voxels`, with those set. `None` if there are no voxels.
+    pub fn from_voxels(voxels: &[Voxel]) -> Option<Self> {
+        let (min, max) = Point3::bounding_box(voxels.iter().copied())?;
+        let mut grid = VoxelGrid::new(min, max, false);
+        for &voxel in voxels {
+            grid[voxel] = true;
+        }
+        Some(grid)
+    }
+
+    /// a grid with one `x,y,z` voxel per line set, e.g. `2,2,2`.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let voxels = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| line.parse().map_err(|e| format!("line {}: {}", i + 1, e)))
+            .collect::<Result<Vec<Voxel>, String>>()?;
+        VoxelGrid::from_voxels(&voxels).ok_or_else(|| "no voxels in the input".into())
+    }
+}
+
+impl<T> Index<Voxel> for VoxelGrid<T> {
+    type Output = T;
+
+    fn index(&self, voxel: Voxel) -> &Self::Output {
+        self.get(voxel)
+            .unwrap_or_else(|| panic!("{} is outside {} to {}", voxel, self.min, self.max))
+    }
+}
+
+impl<T> IndexMut<Voxel> for VoxelGrid<T> {
+    fn index_mut(&mut self, voxel: Voxel) -> &mut Self::Output {
+        let (min, max) = (self.min, self.max);
+        self.get_mut(voxel)
+            .unwrap_or_else(|| panic!("{} is outside {} to {}", voxel, min, max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let mut grid = VoxelGrid::new(Point3::new(-1, 0, 2), Point3::new(1, 1, 3), 0);
+        assert_eq!(grid.positions().count(), 12);
+        assert!(grid.contains(Point3::new(-1, 1, 3)));
+        assert!(!grid.contains(Point3::new(2, 0, 2)));
+        assert_eq!(grid.set(Point3::new(0, 1, 2), 7), Some(0));
+        assert_eq!(grid.set(Point3::new(0, 2, 2), 7), None);
+        assert_eq!(grid[Point3::new(0, 1, 2)], 7);
+        assert_eq!(grid.iter().filter(|(_, &v)| v == 7).count(), 1);
+
+        let corner = Point3::new(-1, 0, 2);
+        assert_eq!(grid.neighbours6(corner).count(), 3);
+        assert_eq!(grid.neighbours26(corner).count(), 7);
+    }
+
+    #[test]
+    fn test_faces() {
+        // a 3x3x3 cube with a hollow center has 54 faces outside and 6 more around the pocket.
+        let cube: Vec<Voxel> = VoxelGrid::new(Point3::new(0, 0, 0), Point3::new(2, 2, 2), ())
+            .positions()
+            .filter(|&voxel| voxel != Point3::new(1, 1, 1))
+            .collect();
+        let grid = VoxelGrid::from_voxels(&cube).unwrap();
+        assert_eq!(grid.surface_faces(|&solid| solid), 60);
+        assert_eq!(grid.exterior_faces(|&solid| solid), 54);
+
+        let outside = grid.flood_fill_outside(|&solid| !solid);
+        assert!(outside[Point3::new(-1, -1, -1)] && outside[Point3::new(3, 1, 1)]);
+        assert!(!outside[Point3::new(1, 1, 1)]);
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = VoxelGrid::parse("1,1,1\n2,1,1\n").unwrap();
+        assert_eq!(
+            (grid.min(), grid.max()),
+            (Point3::new(1, 1, 1), Point3::new(2, 1, 1))
+        );
+        assert_eq!(grid.surface_faces(|&solid| solid), 10);
+        assert_eq!(
+            VoxelGrid::parse("1,1,1\n2,1\n"),
+            Err("line 2: \"2,1\" is not a point x,y,z".into())
+        );
+        assert!(VoxelGrid::parse("").is_err());
+    }
+}
@@ -0,0 +1,98 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::{normalize, read_answer};
+use crate::Puzzle;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// an input to cross-validate a day against, with the answers it is known to produce.
+#[derive(Debug)]
+pub struct NamedInput {
+    pub name: String,
+    pub path: PathBuf,
+    pub expected: [Option<String>; 2],
+}
+
+/// directory holding the named inputs of a day, e.g. `src/y2022/inputs/05/`.
+pub fn named_inputs_dir(puzzle: &Puzzle) -> PathBuf {
+    puzzle.year_dir().join("inputs").join(puzzle.padded_day())
+}
+
+fn read_expected(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|answer| normalize(&answer))
+        .filter(|answer| !answer.is_empty())
+}
+
+/// the default input of a day followed by every `<name>.txt` in its named inputs directory.
+/// known answers for a named input live next to it as `<name>_1.answer` and `<name>_2.answer`.
+pub fn named_inputs(puzzle: &Puzzle) -> Vec<NamedInput> {
+    let mut inputs = vec![];
+
+    let default_path = puzzle.data_path("inputs");
+    if default_path.exists() {
+        inputs.push(NamedInput {
+            name: "default".into(),
+            path: default_path,
+            expected: [read_answer(puzzle, 1), read_answer(puzzle, 2)],
+        });
+    }
+
+    let dir = named_inputs_dir(puzzle);
+    let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+
+    for path in paths {
+        let name = path.file_stem().unwrap().to_string_lossy().to_string();
+        inputs.push(NamedInput {
+            expected: [1, 2]
+                .map(|part| read_expected(&dir.join(format!("{}_{}.answer", name, part)))),
+            name,
+            path,
+        });
+    }
+
+    inputs
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    /// the solution produced an answer, but there is nothing to compare it to.
+    Unknown,
+    /// the solution did not produce an answer.
+    Missing,
+}
+
+pub fn verdict(expected: Option<&str>, actual: Option<&str>) -> Verdict {
+    match (expected, actual) {
+        (_, None) => Verdict::Missing,
+        (None, Some(_)) => Verdict::Unknown,
+        (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+        (Some(_), Some(_)) => Verdict::Fail,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(verdict(Some("3"), Some("3")), Verdict::Pass);
+        assert_eq!(verdict(Some("3"), Some("4")), Verdict::Fail);
+        assert_eq!(verdict(None, Some("4")), Verdict::Unknown);
+        assert_eq!(verdict(Some("3"), None), Verdict::Missing);
+    }
+}
@@ -4,49 +4,173 @@
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
 use std::env;
+use std::fmt::Display;
 use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::Instant;
 
+pub mod answers;
+pub mod calendar;
+pub mod descriptions;
+pub mod generators;
 pub mod helpers;
+pub mod inputs;
+pub mod log;
+pub mod minimize;
+pub mod panics;
+pub mod puzzle;
+pub mod runner;
+pub mod serve;
+pub mod timings;
+
+pub use answers::Answer;
+pub use puzzle::Puzzle;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// runs and prints one part of a day. further implementations of the part can be registered as
+/// `"name" => solver` and picked with `--impl <name>`. `--list-impls` prints them instead of solving,
+/// `--part <n>` skips the other part.
+/// see `run_part` for how answers and panics are printed.
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
+    ($part:expr, $solver:ident, $input:expr $(, $name:literal => $alternative:ident)* $(,)?) => {{
+        let implementations = [
+            ("default", $solver as fn(&str) -> _),
+            $(($name, $alternative as fn(&str) -> _),)*
+        ];
+        let selected = advent_of_code::arg_value("--impl").unwrap_or_else(|| "default".into());
+
+        if !advent_of_code::part_selected($part) {
+            // `--part <n>` runs only that part.
+        } else if advent_of_code::has_flag("--list-impls") {
+            let names: Vec<&str> = implementations.iter().map(|(name, _)| *name).collect();
+            println!("Part {} implementations: {}", $part, names.join(", "));
+        } else {
+            advent_of_code::print_heading($part, &selected);
+            match implementations.iter().find(|(name, _)| *name == selected) {
+                Some((_, solver)) => {
+                    advent_of_code::run_part(*solver, $input, $part, env!("CARGO_BIN_NAME"))
                 }
+                None if advent_of_code::has_flag("--json") => {}
+                None => println!("no implementation named \"{}\".", selected),
             }
         }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+        // a panic in any part still fails the binary, once both parts had their chance to run.
+        if $part == 2 {
+            advent_of_code::panics::exit_if_panicked();
+        }
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
+/// prints the `🎄 Part 1 🎄` heading of a part, naming the implementation if it is not the default one.
+pub fn print_heading(part: u8, implementation: &str) {
+    if has_flag("--json") {
+        return;
+    }
+    if implementation == "default" {
+        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+    } else {
+        println!(
+            "🎄 {}Part {}{} ({}) 🎄",
+            ANSI_BOLD, part, ANSI_RESET, implementation
+        );
+    }
+}
+
+/// runs one part of a day and prints its answer, `not solved.` or its panic. a panicking part is reported
+/// and the next part still runs. with `--json`, a single `answers::json_line` is printed instead.
+pub fn run_part<T: Display>(
+    func: impl FnOnce(&str) -> Option<T>,
+    input: &str,
+    part: u8,
+    bin_name: &str,
+) {
+    panics::reset_current_line();
+    let timer = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| func(input)));
+    let elapsed = timer.elapsed();
+    let json = has_flag("--json");
+
+    match result {
+        Err(payload) => {
+            let panic = panics::capture(payload.as_ref(), input);
+            if json {
+                let message = panic.to_string();
+                let message = message.trim_start_matches("panicked: ");
+                println!("{}", answers::json_line(part, None, None, Some(message)));
+            } else {
+                println!("{}", panic);
+            }
+        }
+        Ok(None) if json => println!("{}", answers::json_line(part, None, None, None)),
+        Ok(None) => println!("not solved."),
+        Ok(Some(result)) => {
+            let answer = answers::normalize(&result.to_string());
+            if json {
+                println!(
+                    "{}",
+                    answers::json_line(part, Some(&answer), Some(elapsed), None)
+                );
+            } else {
+                answers::print_answer(&answer, elapsed);
+            }
+            answers::record_if_requested(bin_name, part, &answer);
+        }
+    }
+}
+
+/// whether `part` should run, i.e. no `--part <n>` was passed or it names `part`.
+pub fn part_selected(part: u8) -> bool {
+    arg_value("--part").is_none_or(|selected| selected == part.to_string())
+}
+
+/// whether `--name` was passed to the current binary.
+pub fn has_flag(name: &str) -> bool {
+    env::args().any(|arg| arg == name)
+}
+
+/// value of a `--name <value>` argument passed to the current binary.
+pub fn arg_value(name: &str) -> Option<String> {
+    let mut args = env::args().skip_while(|arg| arg != name);
+    args.next()?;
+    args.next()
+}
+
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+}
+
+/// contents of a file embedded with the `embed-inputs` feature, e.g. `src/y2022/inputs/05.txt`.
+pub fn embedded_file(path: &Path) -> Option<&'static str> {
+    let key = path.to_string_lossy().replace('\\', "/");
+    embedded::EMBEDDED
+        .iter()
+        .find(|(path, _)| *path == key)
+        .map(|(_, contents)| *contents)
+}
+
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
     let cwd = env::current_dir().unwrap();
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+    let puzzle = Puzzle::new(year, day).expect("invalid puzzle");
+    // `--input <path>` swaps in another input, e.g. one of the named inputs of `--all-inputs`.
+    if let Some(path) = arg_value("--input").filter(|_| folder == "inputs") {
+        return fs::read_to_string(cwd.join(path)).expect("could not open input file");
+    }
+
+    let path = puzzle.data_path(folder);
+    if cfg!(feature = "embed-inputs") {
+        return embedded_file(&path)
+            .unwrap_or_else(|| panic!("\"{}\" was not embedded at build time", path.display()))
+            .to_string();
+    }
 
-    let f = fs::read_to_string(filepath);
+    let f = fs::read_to_string(cwd.join(path));
     f.expect("could not open input file")
 }
 
@@ -77,6 +201,64 @@ pub fn parse_exec_time(output: &str) -> f64 {
     })
 }
 
+/// parses the output of a day binary run with `--list-impls` into the implementations of each part.
+pub fn parse_implementations(output: &str) -> [Vec<String>; 2] {
+    let mut implementations = [vec![], vec![]];
+    for line in output.lines() {
+        let Some((heading, names)) = line.split_once(" implementations: ") else {
+            continue;
+        };
+        let Some(part) = (1..=2).find(|part| heading == format!("Part {}", part)) else {
+            continue;
+        };
+        implementations[part - 1] = names.split(", ").map(String::from).collect();
+    }
+    implementations
+}
+
+/// extracts the answer printed by `solve!` for each part, `None` if a part was not solved.
+/// grids span several lines and end with a line holding only the timing, see `answers::print_answer`.
+pub fn parse_answers(output: &str) -> [Option<String>; 2] {
+    let mut answers = [None, None];
+    let mut lines = output.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let Some(part) = (1..=2).find(|part| line.contains(&format!("Part {}", part))) else {
+            continue;
+        };
+
+        let mut rows = vec![];
+        while let Some(line) = lines.next_if(|line| !line.contains("🎄")) {
+            if let Some((answer, _)) = line.split_once("(elapsed: ") {
+                let answer = answer.trim_end_matches(ANSI_ITALIC).trim_end();
+                if !answer.is_empty() {
+                    rows.push(answer);
+                }
+                answers[part - 1] = Some(rows.join("\n"));
+                break;
+            }
+            rows.push(line);
+        }
+    }
+
+    answers
+}
+
+/// looks up the outcome of a single test in the output of `cargo test`.
+pub fn parse_test_outcome(output: &str, test_name: &str) -> Option<bool> {
+    output.lines().find_map(|l| {
+        let (name, outcome) = l.strip_prefix("test ")?.split_once(" ... ")?;
+        if name != test_name && !name.ends_with(&format!("::{}", test_name)) {
+            return None;
+        }
+        match outcome.trim() {
+            "ok" => Some(true),
+            "FAILED" => Some(false),
+            _ => None,
+        }
+    })
+}
+
 /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
 #[cfg(test)]
 macro_rules! assert_approx_eq {
@@ -122,4 +304,49 @@ mod tests {
             10400.50_f64
         );
     }
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers(&format!(
+                "🎄 {}Part 1{} 🎄\nCMZ {}(elapsed: 74.13ns){}\n🎄 Part 2 🎄\nnot solved.",
+                ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, ANSI_RESET
+            )),
+            [Some("CMZ".to_string()), None]
+        );
+        assert_eq!(
+            parse_answers(&format!(
+                "🎄 Part 1 🎄\nnot solved.\n🎄 Part 2 🎄\n#..#\n.##.\n{}(elapsed: 1.20ms){}\n",
+                ANSI_ITALIC, ANSI_RESET
+            )),
+            [None, Some("#..#\n.##.".to_string())]
+        );
+        assert_eq!(parse_answers(""), [None, None]);
+    }
+
+    #[test]
+    fn test_parse_implementations() {
+        assert_eq!(
+            parse_implementations(
+                "Part 1 implementations: default\nPart 2 implementations: default, naive\n"
+            ),
+            [
+                vec!["default".to_string()],
+                vec!["default".to_string(), "naive".to_string()]
+            ]
+        );
+        assert_eq!(
+            parse_implementations(""),
+            [vec![], vec![]] as [Vec<String>; 2]
+        );
+    }
+
+    #[test]
+    fn test_parse_test_outcome() {
+        let output = "running 2 tests\ntest tests::test_part_one ... ok\ntest tests::test_part_two ... FAILED\n";
+        assert_eq!(parse_test_outcome(output, "test_part_one"), Some(true));
+        assert_eq!(parse_test_outcome(output, "test_part_two"), Some(false));
+        assert_eq!(parse_test_outcome(output, "test_part_three"), None);
+        assert_eq!(parse_test_outcome("", "test_part_one"), None);
+    }
 }
@@ -0,0 +1,64 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::sync::OnceLock;
+
+/// prints to stderr if the day binary was started with `-v` or `-vv`. compiles away in release builds.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) && $crate::log::verbosity() >= 1 {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// prints to stderr if the day binary was started with `-vv`. compiles away in release builds.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) && $crate::log::verbosity() >= 2 {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// `1` for `-v`, `2` for `-vv` or `-v -v`, `0` otherwise.
+pub fn parse_verbosity(args: impl Iterator<Item = String>) -> u8 {
+    let level: usize = args
+        .map(|arg| match arg.as_str() {
+            "-v" | "--verbose" => 1,
+            "-vv" => 2,
+            _ => 0,
+        })
+        .sum();
+    level.min(2) as u8
+}
+
+pub fn verbosity() -> u8 {
+    static VERBOSITY: OnceLock<u8> = OnceLock::new();
+    *VERBOSITY.get_or_init(|| parse_verbosity(env::args()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn test_parse_verbosity() {
+        assert_eq!(parse_verbosity(args(&["2022_08"])), 0);
+        assert_eq!(parse_verbosity(args(&["2022_08", "-v"])), 1);
+        assert_eq!(parse_verbosity(args(&["2022_08", "-vv", "--record"])), 2);
+        assert_eq!(parse_verbosity(args(&["2022_08", "-v", "-vv"])), 2);
+        assert_eq!(parse_verbosity(args(&["2022_08", "--input", "-v.txt"])), 0);
+    }
+}
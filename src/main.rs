@@ -2,44 +2,78 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use advent_of_code::puzzle::years;
+use advent_of_code::{Puzzle, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 use std::process::Command;
 
+fn run_day(puzzle: &Puzzle) -> f64 {
+    let bin_name = puzzle.bin_name();
+
+    println!("---------------");
+    println!("{}| Day {} |{}", ANSI_BOLD, puzzle, ANSI_RESET);
+    println!("---------------");
+
+    let output = if puzzle.module_path().exists() {
+        let mut args = vec!["run", "--bin", &bin_name];
+        if cfg!(not(debug_assertions)) {
+            args.push("--release");
+        }
+        if cfg!(feature = "embed-inputs") {
+            args.extend(["--features", "embed-inputs"]);
+        }
+
+        let cmd = Command::new("cargo").args(&args).output().unwrap();
+        String::from_utf8(cmd.stdout).unwrap()
+    } else {
+        String::new()
+    };
+
+    let is_empty = output.is_empty();
+
+    println!(
+        "{}",
+        if is_empty {
+            "Not solved."
+        } else {
+            output.trim()
+        }
+    );
+
+    if is_empty {
+        0_f64
+    } else {
+        advent_of_code::parse_exec_time(&output)
+    }
+}
+
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
-
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
-
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
-            }
-        })
-        .sum();
+    // `cargo all 2022` only runs the given year.
+    let selected_year: Option<u16> = pico_args::Arguments::from_env()
+        .opt_free_from_str()
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to process arguments: {}", e);
+            std::process::exit(1);
+        });
+
+    let years: Vec<u16> = years()
+        .into_iter()
+        .filter(|year| selected_year.is_none() || selected_year == Some(*year))
+        .collect();
+
+    let mut total = 0_f64;
+
+    for year in years {
+        let year_total: f64 = (1..=25)
+            .filter_map(|day| Puzzle::new(year, day))
+            .map(|puzzle| run_day(&puzzle))
+            .sum();
+
+        println!(
+            "{}Total {}:{} {}{:.2}ms{}",
+            ANSI_BOLD, year, ANSI_RESET, ANSI_ITALIC, year_total, ANSI_RESET
+        );
+        total += year_total;
+    }
 
     println!(
         "{}Total:{} {}{:.2}ms{}",
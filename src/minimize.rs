@@ -0,0 +1,123 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+/// what a minimized input is cut into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    /// paragraphs separated by an empty line, e.g. the elves of day 1 or the monkeys of day 11.
+    Blocks,
+    Lines,
+}
+
+impl Granularity {
+    fn separator(&self) -> &'static str {
+        match self {
+            Granularity::Blocks => "\n\n",
+            Granularity::Lines => "\n",
+        }
+    }
+
+    pub fn split(&self, input: &str) -> Vec<String> {
+        input
+            .trim_end_matches('\n')
+            .split(self.separator())
+            .map(String::from)
+            .collect()
+    }
+
+    pub fn join(&self, units: &[String]) -> String {
+        let mut input = units.join(self.separator());
+        input.push('\n');
+        input
+    }
+}
+
+/// delta debugging (ddmin): removes ever smaller chunks of `units` for as long as `still_fails` holds.
+/// the result is 1-minimal: removing any single unit makes the failure go away.
+pub fn ddmin<T: Clone>(mut units: Vec<T>, mut still_fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut chunks = 2;
+
+    while units.len() >= 2 {
+        let chunk_len = units.len().div_ceil(chunks);
+        let mut reduced = false;
+
+        for start in (0..units.len()).step_by(chunk_len) {
+            let complement: Vec<T> = units[..start]
+                .iter()
+                .chain(units[(start + chunk_len).min(units.len())..].iter())
+                .cloned()
+                .collect();
+            if still_fails(&complement) {
+                units = complement;
+                chunks = (chunks - 1).max(2);
+                reduced = true;
+                break;
+            }
+        }
+
+        if !reduced {
+            if chunks >= units.len() {
+                break;
+            }
+            chunks = (chunks * 2).min(units.len());
+        }
+    }
+
+    units
+}
+
+/// minimizes `input` block by block, then the lines of whatever blocks are left. a pass is skipped when there is
+/// only one unit to remove, e.g. an input without blank lines has a single block.
+pub fn minimize(input: &str, mut still_fails: impl FnMut(&str) -> bool) -> String {
+    let mut input = input.to_string();
+    for granularity in [Granularity::Blocks, Granularity::Lines] {
+        let units = granularity.split(&input);
+        if units.len() < 2 {
+            continue;
+        }
+        let units = ddmin(units, |units| still_fails(&granularity.join(units)));
+        input = granularity.join(&units);
+    }
+    input
+}
+
+/// location of the first panic in the stderr of a rust binary, e.g. `src/y2022/15.rs:69:51`.
+pub fn panic_location(stderr: &str) -> Option<String> {
+    stderr.lines().find_map(|line| {
+        let rest = line.split(" panicked at ").nth(1)?;
+        // newer toolchains end the line with `:`, older ones put the message first: `'msg', src/...:1:2`.
+        let location = rest.trim_end_matches(':').rsplit(", ").next()?;
+        Some(location.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ddmin() {
+        let units: Vec<u32> = (0..100).collect();
+        let minimal = ddmin(units, |units| units.contains(&13) && units.contains(&77));
+        assert_eq!(minimal, vec![13, 77]);
+    }
+
+    #[test]
+    fn test_minimize_blocks_then_lines() {
+        let input = "1\n2\n\n3\n4\n\n5\n6\n";
+        let minimal = minimize(input, |input| input.contains("4\n"));
+        assert_eq!(minimal, "4\n");
+    }
+
+    #[test]
+    fn test_panic_location() {
+        let stderr = "thread 'main' panicked at src/y2022/15.rs:69:51:\ncalled `Option::unwrap()` on a `None` value\n";
+        assert_eq!(panic_location(stderr), Some("src/y2022/15.rs:69:51".into()));
+        let old =
+            "thread 'main' panicked at 'attempt to subtract with overflow', src/y2022/14.rs:40:9\n";
+        assert_eq!(panic_location(old), Some("src/y2022/14.rs:40:9".into()));
+        assert_eq!(panic_location("all good"), None);
+    }
+}
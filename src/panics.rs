@@ -0,0 +1,109 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt::{self, Display};
+use std::process;
+
+/// exit status of a day binary with a panicking part, the same a plain rust panic exits with.
+pub const PANIC_EXIT_CODE: i32 = 101;
+
+thread_local! {
+    static CURRENT_LINE: Cell<Option<usize>> = const { Cell::new(None) };
+    static PANICKED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// like `input.lines()`, but remembers the line being processed so a panic can point at it.
+pub fn tracked_lines(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .lines()
+        .inspect(|line| CURRENT_LINE.with(|current| current.set(Some(line.as_ptr() as usize))))
+}
+
+pub fn reset_current_line() {
+    CURRENT_LINE.with(|current| current.set(None));
+}
+
+/// the 1-based number and content of the last tracked line, if it is a line of `input`.
+pub fn current_line(input: &str) -> Option<(usize, &str)> {
+    let address = CURRENT_LINE.with(|current| current.get())?;
+    let offset = address.checked_sub(input.as_ptr() as usize)?;
+    if offset >= input.len() || !input.is_char_boundary(offset) {
+        return None;
+    }
+    let number = input[..offset].matches('\n').count() + 1;
+    Some((number, input[offset..].lines().next().unwrap_or_default()))
+}
+
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".into()
+    }
+}
+
+/// a caught panic of a part, with the input line it was processing if the day tracks its lines.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    pub line: Option<(usize, String)>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "panicked: {}", self.message)?;
+        if let Some((number, line)) = &self.line {
+            write!(f, "\nwhile processing line {}: {}", number, line)?;
+        }
+        Ok(())
+    }
+}
+
+/// records that a part panicked, so `exit_if_panicked` fails the binary later on.
+pub fn capture(payload: &(dyn Any + Send), input: &str) -> Panic {
+    PANICKED.with(|panicked| panicked.set(true));
+    Panic {
+        message: panic_message(payload),
+        line: current_line(input).map(|(number, line)| (number, line.to_string())),
+    }
+}
+
+/// exits with `PANIC_EXIT_CODE` if any part panicked, so callers such as `--all-inputs` still see the crash.
+pub fn exit_if_panicked() {
+    if PANICKED.with(|panicked| panicked.get()) {
+        process::exit(PANIC_EXIT_CODE);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_line() {
+        let input = "1-2,3-4\n5-x,7-8\n9-9,1-1\n";
+        reset_current_line();
+        assert_eq!(current_line(input), None);
+
+        let mut lines = tracked_lines(input);
+        lines.next();
+        lines.next();
+        assert_eq!(current_line(input), Some((2, "5-x,7-8")));
+        assert_eq!(current_line("another input"), None);
+
+        // lines of a slice of the input are found in the full input.
+        tracked_lines(&input[16..]).next();
+        assert_eq!(current_line(input), Some((3, "9-9,1-1")));
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let payload = std::panic::catch_unwind(|| panic!("bad {}", "input")).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "bad input");
+    }
+}
@@ -0,0 +1,161 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// A single puzzle of the calendar, addressed as `2022/05` on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Puzzle {
+    /// the number of puzzles in the event of `year`: 25 up to 2024, 12 from 2025 on.
+    pub fn days_in(year: u16) -> u8 {
+        if year >= 2025 {
+            12
+        } else {
+            25
+        }
+    }
+
+    pub fn new(year: u16, day: u8) -> Option<Self> {
+        if (2015..=9999).contains(&year) && (1..=Puzzle::days_in(year)).contains(&day) {
+            Some(Puzzle { year, day })
+        } else {
+            None
+        }
+    }
+
+    /// parses `2022/05`, or a bare day such as `05` which is resolved against `default_year`.
+    pub fn parse_with_default(s: &str, default_year: Option<u16>) -> Result<Self, String> {
+        let (year, day) = match s.split_once('/') {
+            Some((year, day)) => (
+                year.parse()
+                    .map_err(|_| format!("invalid year \"{}\"", year))?,
+                day,
+            ),
+            None => (
+                default_year.ok_or_else(|| {
+                    format!("no year found for day \"{}\", use e.g. `2022/{}`", s, s)
+                })?,
+                s,
+            ),
+        };
+        let day = day
+            .parse()
+            .map_err(|_| format!("invalid day \"{}\"", day))?;
+        Puzzle::new(year, day).ok_or_else(|| format!("{}/{} is not a puzzle", year, day))
+    }
+
+    /// day binaries are named `<year>_<day>`, e.g. `2022_05`.
+    pub fn from_bin_name(bin_name: &str) -> Option<Self> {
+        let (year, day) = bin_name.split_once('_')?;
+        Puzzle::new(year.parse().ok()?, day.parse().ok()?)
+    }
+
+    pub fn bin_name(&self) -> String {
+        format!("{}_{:02}", self.year, self.day)
+    }
+
+    pub fn padded_day(&self) -> String {
+        format!("{:02}", self.day)
+    }
+
+    pub fn year_dir(&self) -> PathBuf {
+        year_dir(self.year)
+    }
+
+    pub fn module_path(&self) -> PathBuf {
+        self.year_dir().join(format!("{}.rs", self.padded_day()))
+    }
+
+    /// path of this day's file in a data folder such as `inputs` or `examples`.
+    pub fn data_path(&self, folder: &str) -> PathBuf {
+        self.year_dir()
+            .join(folder)
+            .join(format!("{}.txt", self.padded_day()))
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{:02}", self.year, self.day)
+    }
+}
+
+impl FromStr for Puzzle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Puzzle::parse_with_default(s, default_year())
+    }
+}
+
+pub fn year_dir(year: u16) -> PathBuf {
+    PathBuf::from("src").join(format!("y{}", year))
+}
+
+/// all years that have a `src/y<year>` directory, in ascending order.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = fs::read_dir("src")
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let entry = entry.ok()?;
+                    if !entry.file_type().ok()?.is_dir() {
+                        return None;
+                    }
+                    entry.file_name().to_str()?.strip_prefix('y')?.parse().ok()
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    years.sort_unstable();
+    years
+}
+
+/// the year bare days refer to: `AOC_YEAR` if set, otherwise the latest year in `src`.
+pub fn default_year() -> Option<u16> {
+    match std::env::var("AOC_YEAR") {
+        Ok(year) => year.parse().ok(),
+        Err(_) => years().last().copied(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_puzzle() {
+        assert_eq!(
+            Puzzle::parse_with_default("2022/05", None),
+            Ok(Puzzle { year: 2022, day: 5 })
+        );
+        assert_eq!(
+            Puzzle::parse_with_default("7", Some(2021)),
+            Ok(Puzzle { year: 2021, day: 7 })
+        );
+        assert!(Puzzle::parse_with_default("7", None).is_err());
+        assert!(Puzzle::parse_with_default("2022/26", None).is_err());
+        assert!(Puzzle::parse_with_default("22/01", None).is_err());
+    }
+
+    #[test]
+    fn test_bin_name() {
+        let puzzle = Puzzle::new(2022, 5).unwrap();
+        assert_eq!(puzzle.bin_name(), "2022_05");
+        assert_eq!(Puzzle::from_bin_name("2022_05"), Some(puzzle));
+        assert_eq!(Puzzle::from_bin_name("scaffold"), None);
+        // the events from 2025 on have 12 days.
+        assert!(Puzzle::new(2024, 25).is_some() && Puzzle::new(2025, 12).is_some());
+        assert_eq!(Puzzle::new(2025, 13), None);
+        assert_eq!(puzzle.to_string(), "2022/05");
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::minimize::panic_location;
+use crate::parse_answers;
+use std::env;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Output, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Answers([Option<String>; 2]),
+    /// carries the panic location, if the binary reported one.
+    Panicked(Option<String>),
+    TimedOut,
+}
+
+/// path of the compiled binary `bin`, built or not.
+pub fn binary_path(bin: &str, release: bool) -> PathBuf {
+    let target_dir = env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".into());
+    let profile = if release { "release" } else { "debug" };
+    let binary = Path::new(&target_dir).join(profile).join(bin);
+    env::current_dir().unwrap().join(binary)
+}
+
+/// builds all of `bins` with a single cargo invocation.
+pub fn build_all(bins: &[String], release: bool) -> Result<(), String> {
+    let mut cmd = Command::new("cargo");
+    cmd.arg("build");
+    for bin in bins {
+        cmd.args(["--bin", bin]);
+    }
+    if release {
+        cmd.arg("--release");
+    }
+    let status = cmd
+        .status()
+        .map_err(|e| format!("failed to spawn cargo: {}", e))?;
+    if !status.success() {
+        return Err(format!("could not build \"{}\"", bins.join("\", \"")));
+    }
+    Ok(())
+}
+
+/// builds `bin` and returns the path of the compiled binary.
+pub fn build(bin: &str, release: bool) -> Result<PathBuf, String> {
+    build_all(&[bin.to_string()], release)?;
+    Ok(binary_path(bin, release))
+}
+
+/// runs compiled day binaries against inputs written to a scratch directory, which is removed on drop.
+pub struct Runner {
+    scratch: PathBuf,
+    timeout: Option<Duration>,
+    pub runs: usize,
+}
+
+impl Runner {
+    pub fn new(timeout: Option<Duration>) -> Result<Self, String> {
+        // runners of the same process, e.g. the request threads of `serve`, each get their own directory.
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let scratch = env::temp_dir().join(format!(
+            "aoc_runner_{}_{}",
+            process::id(),
+            NEXT_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&scratch)
+            .map_err(|e| format!("could not create \"{}\": {}", scratch.display(), e))?;
+        Ok(Runner {
+            scratch,
+            timeout,
+            runs: 0,
+        })
+    }
+
+    /// runs `binary` with `--input <input>` and `args`. returns `None` if the run timed out.
+    pub fn execute(
+        &mut self,
+        binary: &Path,
+        input: &str,
+        args: &[&str],
+    ) -> Result<Option<Output>, String> {
+        self.runs += 1;
+        let input_path = self.scratch.join("input.txt");
+        let stdout_path = self.scratch.join("stdout.txt");
+        let stderr_path = self.scratch.join("stderr.txt");
+        fs::write(&input_path, input).map_err(|e| e.to_string())?;
+
+        // output goes to files so a chatty binary cannot block on a full pipe while we wait for it.
+        let mut child = Command::new(binary)
+            .arg("--input")
+            .arg(&input_path)
+            .args(args)
+            .stdout(Stdio::from(
+                File::create(&stdout_path).map_err(|e| e.to_string())?,
+            ))
+            .stderr(Stdio::from(
+                File::create(&stderr_path).map_err(|e| e.to_string())?,
+            ))
+            .spawn()
+            .map_err(|e| format!("failed to spawn \"{}\": {}", binary.display(), e))?;
+
+        let started = Instant::now();
+        let status = loop {
+            if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+                break status;
+            }
+            if self
+                .timeout
+                .is_some_and(|timeout| started.elapsed() > timeout)
+            {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Ok(None);
+            }
+            thread::sleep(Duration::from_millis(5));
+        };
+
+        Ok(Some(Output {
+            status,
+            stdout: fs::read(&stdout_path).unwrap_or_default(),
+            stderr: fs::read(&stderr_path).unwrap_or_default(),
+        }))
+    }
+
+    pub fn run(&mut self, binary: &Path, input: &str, args: &[&str]) -> Result<Outcome, String> {
+        let Some(output) = self.execute(binary, input, args)? else {
+            return Ok(Outcome::TimedOut);
+        };
+
+        if output.status.success() {
+            Ok(Outcome::Answers(parse_answers(&String::from_utf8_lossy(
+                &output.stdout,
+            ))))
+        } else {
+            Ok(Outcome::Panicked(panic_location(&String::from_utf8_lossy(
+                &output.stderr,
+            ))))
+        }
+    }
+}
+
+impl Drop for Runner {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.scratch);
+    }
+}
@@ -0,0 +1,289 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::json_string;
+use crate::puzzle::default_year;
+use crate::Puzzle;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// the largest input accepted, far above any puzzle input but small enough to hold in memory.
+pub const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// how long a connection may stay silent before it is dropped, so a stalled client does not hold a thread.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// solves one part of a puzzle for an input. returns the `--json` line of the part, e.g.
+/// `{"part":1,"answer":"24000","elapsed_ns":1200,"panic":null}`, or `None` if the day has no solution.
+pub type Solver = dyn Fn(&Puzzle, u8, &str) -> Result<Option<String>, String> + Send + Sync;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn error(status: u16, message: &str) -> Self {
+        Response {
+            status,
+            body: format!("{{\"error\":{}}}", json_string(message)),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+/// reads the request line, the headers and a body of `Content-Length` bytes.
+/// the error is the response to send instead, e.g. a 413 for a body above `MAX_BODY_BYTES`.
+pub fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let bad_request = |e: &dyn std::fmt::Display| Response::error(400, &e.to_string());
+    let mut request_line = String::new();
+    reader
+        .read_line(&mut request_line)
+        .map_err(|e| bad_request(&e))?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(bad_request(&format!(
+            "malformed request line \"{}\"",
+            request_line.trim()
+        )));
+    };
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).map_err(|e| bad_request(&e))?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().map_err(|_| {
+                    bad_request(&format!("invalid content length \"{}\"", value.trim()))
+                })?;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_BYTES {
+        return Err(Response::error(
+            413,
+            &format!(
+                "a body of {} bytes is above the limit of {}",
+                content_length, MAX_BODY_BYTES
+            ),
+        ));
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(|e| bad_request(&e))?;
+    Ok(Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        body: String::from_utf8(body).map_err(|_| bad_request(&"body is not valid utf-8"))?,
+    })
+}
+
+/// resolves `/day/{n}/part/{p}` against the default year, or `/{year}/day/{n}/part/{p}`.
+pub fn route(path: &str) -> Result<(Puzzle, u8), Response> {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let (puzzle, part) = match segments[..] {
+        ["day", day, "part", part] => (Puzzle::parse_with_default(day, default_year()), part),
+        [year, "day", day, "part", part] => (
+            Puzzle::parse_with_default(&format!("{}/{}", year, day), None),
+            part,
+        ),
+        _ => return Err(Response::error(404, &format!("no route for \"{}\"", path))),
+    };
+
+    let puzzle = puzzle.map_err(|e| Response::error(404, &e))?;
+    match part.parse() {
+        Ok(part @ (1 | 2)) => Ok((puzzle, part)),
+        _ => Err(Response::error(
+            404,
+            &format!("\"{}\" is not a part, use 1 or 2", part),
+        )),
+    }
+}
+
+pub fn handle(request: &Request, solver: &Solver) -> Response {
+    let (puzzle, part) = match route(&request.path) {
+        Ok(route) => route,
+        Err(response) => return response,
+    };
+    if request.method != "POST" {
+        return Response::error(405, "post the puzzle input to this path");
+    }
+
+    match solver(&puzzle, part, &request.body) {
+        // the part's json line, prefixed with the puzzle it answers.
+        Ok(Some(line)) => match line.strip_prefix('{') {
+            Some(fields) => Response {
+                status: 200,
+                body: format!(
+                    "{{\"year\":{},\"day\":{},{}",
+                    puzzle.year, puzzle.day, fields
+                ),
+            },
+            None => Response::error(500, &format!("unexpected solver output \"{}\"", line)),
+        },
+        Ok(None) => Response::error(404, &format!("{} has no solution", puzzle)),
+        Err(e) => Response::error(500, &e),
+    }
+}
+
+fn write_response(stream: &mut impl Write, response: &Response) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+fn handle_connection(stream: TcpStream, solver: &Solver) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let response = match read_request(&mut reader) {
+        Ok(request) => handle(&request, solver),
+        Err(response) => response,
+    };
+    write_response(&mut &stream, &response)
+}
+
+/// answers requests on `listener` forever, each connection on its own thread.
+pub fn serve(listener: TcpListener, solver: Arc<Solver>) {
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else {
+            continue;
+        };
+        let solver = Arc::clone(&solver);
+        thread::spawn(move || {
+            if let Err(e) = handle_connection(stream, solver.as_ref()) {
+                eprintln!("could not answer request: {}", e);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::json_line;
+    use std::io::Read;
+    use std::net::SocketAddr;
+    use std::time::Duration;
+
+    /// "solves" a part by counting the lines of the input. only 2022/01 has a solution.
+    fn start_server() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let solver: Arc<Solver> = Arc::new(|puzzle: &Puzzle, part: u8, input: &str| {
+            if puzzle.day != 1 {
+                return Ok(None);
+            }
+            let answer = input.lines().count().to_string();
+            Ok(Some(json_line(
+                part,
+                Some(&answer),
+                Some(Duration::from_nanos(42)),
+                None,
+            )))
+        });
+        thread::spawn(move || serve(listener, solver));
+        address
+    }
+
+    fn request(address: SocketAddr, method: &str, path: &str, body: &str) -> String {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_serve() {
+        let address = start_server();
+
+        let response = request(address, "POST", "/2022/day/1/part/2", "1\n2\n\n3\n");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with(
+            "\r\n\r\n{\"year\":2022,\"day\":1,\"part\":2,\"answer\":\"4\",\"elapsed_ns\":42,\"panic\":null}"
+        ));
+
+        let response = request(address, "POST", "/2022/day/2/part/1", "");
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+        assert!(response.ends_with("{\"error\":\"2022/02 has no solution\"}"));
+
+        let response = request(address, "GET", "/2022/day/1/part/1", "");
+        assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+    }
+
+    #[test]
+    fn test_route() {
+        assert_eq!(
+            route("/2022/day/5/part/1"),
+            Ok((Puzzle::new(2022, 5).unwrap(), 1))
+        );
+        assert_eq!(route("/2022/day/26/part/1").unwrap_err().status, 404);
+        assert_eq!(route("/2022/day/5/part/3").unwrap_err().status, 404);
+        assert_eq!(route("/2022/day/5").unwrap_err().status, 404);
+    }
+
+    #[test]
+    fn test_read_request() {
+        let raw = "POST /day/1/part/1 HTTP/1.1\r\ncontent-length: 5\r\n\r\n1\n2\n\nignored";
+        assert_eq!(
+            read_request(&mut raw.as_bytes()),
+            Ok(Request {
+                method: "POST".into(),
+                path: "/day/1/part/1".into(),
+                body: "1\n2\n\n".into(),
+            })
+        );
+
+        let raw = format!(
+            "POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_BYTES + 1
+        );
+        assert_eq!(read_request(&mut raw.as_bytes()).unwrap_err().status, 413);
+        let raw = "POST / HTTP/1.1\r\nContent-Length: lots\r\n\r\n";
+        assert_eq!(
+            read_request(&mut raw.as_bytes()),
+            Err(Response::error(400, "invalid content length \"lots\""))
+        );
+    }
+}
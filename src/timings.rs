@@ -0,0 +1,149 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::Puzzle;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// when work on a day started and when each part first got a recorded answer, in seconds since the epoch.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub started: Option<u64>,
+    pub solved: [Option<u64>; 2],
+}
+
+impl Timing {
+    /// parses `started <secs>`, `part1 <secs>` and `part2 <secs>` lines. unknown lines are ignored.
+    pub fn parse(s: &str) -> Self {
+        let mut timing = Timing::default();
+        for line in s.lines() {
+            let Some((key, value)) = line.split_once(' ') else {
+                continue;
+            };
+            let Ok(value) = value.trim().parse() else {
+                continue;
+            };
+            match key {
+                "started" => timing.started = Some(value),
+                "part1" => timing.solved[0] = Some(value),
+                "part2" => timing.solved[1] = Some(value),
+                _ => {}
+            }
+        }
+        timing
+    }
+
+    /// time from the start of the day to the first recorded answer of `part`.
+    pub fn time_to(&self, part: u8) -> Option<Duration> {
+        let solved = self.solved[part as usize - 1]?;
+        Some(Duration::from_secs(solved.checked_sub(self.started?)?))
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines = [
+            ("started", self.started),
+            ("part1", self.solved[0]),
+            ("part2", self.solved[1]),
+        ];
+        for (key, value) in lines {
+            if let Some(value) = value {
+                writeln!(f, "{} {}", key, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|since| since.as_secs())
+        .unwrap_or(0)
+}
+
+pub fn timing_path(puzzle: &Puzzle) -> PathBuf {
+    puzzle
+        .year_dir()
+        .join("timings")
+        .join(format!("{}.txt", puzzle.padded_day()))
+}
+
+pub fn read_timing(puzzle: &Puzzle) -> Timing {
+    fs::read_to_string(timing_path(puzzle))
+        .map(|timing| Timing::parse(&timing))
+        .unwrap_or_default()
+}
+
+fn write_timing(puzzle: &Puzzle, timing: &Timing) -> io::Result<()> {
+    let path = timing_path(puzzle);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, timing.to_string())
+}
+
+/// records `now` as the start of the day, unless it already has one. used by `cargo scaffold`.
+pub fn record_start(puzzle: &Puzzle, now: SystemTime) -> io::Result<()> {
+    let mut timing = read_timing(puzzle);
+    if timing.started.is_none() {
+        timing.started = Some(seconds(now));
+        write_timing(puzzle, &timing)?;
+    }
+    Ok(())
+}
+
+/// records `now` as the time `part` was solved, unless it was solved before. returns whether it was recorded.
+pub fn record_solved(puzzle: &Puzzle, part: u8, now: SystemTime) -> io::Result<bool> {
+    let mut timing = read_timing(puzzle);
+    let solved = &mut timing.solved[part as usize - 1];
+    if solved.is_some() {
+        return Ok(false);
+    }
+    *solved = Some(seconds(now));
+    write_timing(puzzle, &timing)?;
+    Ok(true)
+}
+
+/// a coarse duration for humans, e.g. `42s`, `17m 05s`, `2h 03m` or `3d 04h`.
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (days, hours, minutes) = (secs / 86400, secs / 3600 % 24, secs / 60 % 60);
+    if days > 0 {
+        format!("{}d {:02}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {:02}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {:02}s", minutes, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timing() {
+        let timing = Timing::parse("started 1000\npart1 1600\n");
+        assert_eq!(timing.time_to(1), Some(Duration::from_secs(600)));
+        assert_eq!(timing.time_to(2), None);
+        assert_eq!(Timing::parse(&timing.to_string()), timing);
+
+        // days scaffolded before timings were tracked have no start.
+        assert_eq!(Timing::parse("part2 1600").time_to(2), None);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(42)), "42s");
+        assert_eq!(format_duration(Duration::from_secs(17 * 60 + 5)), "17m 05s");
+        assert_eq!(format_duration(Duration::from_secs(7380)), "2h 03m");
+        assert_eq!(format_duration(Duration::from_secs(273600)), "3d 04h");
+    }
+}
@@ -36,7 +36,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
+    let input = &advent_of_code::read_file("inputs", 2022, 1);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -47,13 +47,23 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file("examples", 2022, 1);
         assert_eq!(part_one(&input), None);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file("examples", 2022, 1);
         assert_eq!(part_two(&input), None);
     }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 1, size);
+            assert!(input
+                .lines()
+                .all(|line| line.is_empty() || line.parse::<u32>().is_ok()));
+        }
+    }
 }
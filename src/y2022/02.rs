@@ -157,7 +157,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
+    let input = &advent_of_code::read_file("inputs", 2022, 2);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -168,13 +168,23 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file("examples", 2022, 2);
         assert_eq!(part_one(&input), None);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file("examples", 2022, 2);
         assert_eq!(part_two(&input), None);
     }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 2, size);
+            for line in input.lines() {
+                assert!(line.parse::<Round>().is_ok(), "{:?}", line);
+            }
+        }
+    }
 }
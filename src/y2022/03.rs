@@ -0,0 +1,70 @@
+use advent_of_code::helpers::BitSet64;
+
+/// the item types of a rucksack, with each item's priority minus one as its bit.
+fn items(sack: &str) -> BitSet64 {
+    BitSet64::from_letters(sack).unwrap()
+}
+
+fn priority(items: BitSet64) -> u32 {
+    items.iter().map(|item| item as u32 + 1).sum()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let sum = input
+        .lines()
+        .map(|line| {
+            let (first, second) = line.split_at(line.len() / 2);
+            priority(items(first) & items(second))
+        })
+        .sum();
+    Some(sum)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let lines: Vec<_> = input.lines().collect();
+    let score: u32 = lines.chunks(3).map(check_group).sum();
+    Some(score)
+}
+
+fn check_group(chunk: &[&str]) -> u32 {
+    let badge = chunk
+        .iter()
+        .map(|sack| items(sack))
+        .reduce(|common, items| common & items)
+        .unwrap_or_default();
+    priority(badge)
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2022, 3);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 3);
+        assert_eq!(part_one(&input), Some(157));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 3);
+        assert_eq!(part_two(&input), Some(70));
+    }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 3, size);
+            for line in input.lines() {
+                assert_eq!(line.len() % 2, 0, "{:?}", line);
+                assert!(BitSet64::from_letters(line).is_ok(), "{:?}", line);
+            }
+        }
+    }
+}
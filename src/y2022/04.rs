@@ -0,0 +1,73 @@
+use advent_of_code::helpers::Interval;
+
+fn parse_range(s: &str) -> Interval<u32> {
+    let mut parts = s.split('-');
+    let lower = parts.next().unwrap().parse().unwrap();
+    let upper = parts.next().unwrap().parse().unwrap();
+    Interval::new(lower, upper)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut count = 0;
+    for line in advent_of_code::panics::tracked_lines(input) {
+        let mut ranges = line.split(',');
+        let (a, b) = (
+            parse_range(ranges.next().unwrap()),
+            parse_range(ranges.next().unwrap()),
+        );
+        if a.contains_interval(&b) || b.contains_interval(&a) {
+            count += 1;
+        };
+    }
+    Some(count as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut count = 0;
+    for line in advent_of_code::panics::tracked_lines(input) {
+        let mut ranges = line.split(',');
+        let (a, b) = (
+            parse_range(ranges.next().unwrap()),
+            parse_range(ranges.next().unwrap()),
+        );
+        if a.overlaps(&b) {
+            count += 1;
+        };
+    }
+    Some(count as u32)
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2022, 4);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 4);
+        assert_eq!(part_one(&input), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 4);
+        assert_eq!(part_two(&input), Some(4));
+    }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 4, size);
+            for line in input.lines() {
+                let (first, second) = line.split_once(',').unwrap();
+                parse_range(first);
+                parse_range(second);
+            }
+        }
+    }
+}
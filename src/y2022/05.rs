@@ -132,7 +132,7 @@ pub fn part_two(input: &str) -> Option<String> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
+    let input = &advent_of_code::read_file("inputs", 2022, 5);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -143,13 +143,25 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = advent_of_code::read_file("examples", 2022, 5);
         assert_eq!(part_one(&input), Some("CMZ".to_string()));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = advent_of_code::read_file("examples", 2022, 5);
         assert_eq!(part_two(&input), Some("MCD".to_string()));
     }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 5, size);
+            let (stacks, moves) = input.split_once("\n\n").unwrap();
+            assert!(!build_stacks(stacks).is_empty());
+            for line in moves.lines() {
+                assert!(line.parse::<Move>().is_ok(), "{:?}", line);
+            }
+        }
+    }
 }
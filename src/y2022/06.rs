@@ -0,0 +1,71 @@
+use advent_of_code::helpers::{letter_index, BitSet64};
+use std::collections::HashSet;
+
+/// end of the first window of `len` distinct letters, tracking the window as a bitset.
+fn marker_end_bits(input: &str, len: usize) -> Option<u32> {
+    let letters: Vec<usize> = input
+        .trim()
+        .chars()
+        .map(|c| letter_index(c).expect("the datastream is made of letters"))
+        .collect();
+    letters
+        .windows(len)
+        .position(|window| window.iter().copied().collect::<BitSet64>().len() == len)
+        .map(|idx| (idx + len) as u32)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    marker_end_bits(input, 4)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    marker_end_bits(input, 14)
+}
+
+/// end of the first window of `len` distinct characters.
+fn marker_end(input: &str, len: usize) -> Option<u32> {
+    let chars: Vec<char> = input.chars().collect();
+    chars
+        .windows(len)
+        .position(|window| window.iter().collect::<HashSet<_>>().len() == len)
+        .map(|idx| (idx + len) as u32)
+}
+
+fn part_one_windows(input: &str) -> Option<u32> {
+    marker_end(input, 4)
+}
+
+fn part_two_windows(input: &str) -> Option<u32> {
+    marker_end(input, 14)
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2022, 6);
+    advent_of_code::solve!(1, part_one, input, "windows" => part_one_windows);
+    advent_of_code::solve!(2, part_two, input, "windows" => part_two_windows);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 6);
+        assert_eq!(part_one(&input), Some(7));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 6);
+        assert_eq!(part_two(&input), Some(19));
+    }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 6, size);
+            assert!(marker_end_bits(&input, 14).is_some());
+        }
+    }
+}
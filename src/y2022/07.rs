@@ -151,7 +151,7 @@ pub fn part_two(input: &str) -> Option<usize> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
+    let input = &advent_of_code::read_file("inputs", 2022, 7);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -162,13 +162,21 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_one(&input), Some(95437));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_two(&input), Some(24933642));
     }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 7, size);
+            assert!(part_one(&input).is_some());
+        }
+    }
 }
@@ -0,0 +1,79 @@
+use advent_of_code::helpers::{Grid, Pos, OFFSETS_4};
+
+fn parse(input: &str) -> Grid<u32> {
+    Grid::parse(input, |c| c.to_digit(10)).unwrap()
+}
+
+// a tree is visible if all trees between it and one of the edges are shorter
+fn is_visible(grid: &Grid<u32>, pos: Pos) -> bool {
+    OFFSETS_4.iter().any(|&direction| {
+        grid.ray(pos, direction)
+            .all(|other| grid[other] < grid[pos])
+    })
+}
+
+fn scenic_score(grid: &Grid<u32>, pos: Pos) -> usize {
+    // Check how many trees are visible from this tree
+    let tree_height = grid[pos];
+    let scores = OFFSETS_4.map(|direction| {
+        let mut score = 0;
+        for other in grid.ray(pos, direction) {
+            score += 1;
+            if grid[other] >= tree_height {
+                break;
+            }
+        }
+        score
+    });
+    advent_of_code::trace!(
+        "checking tree with height: {} at pos {:?} \nup_score: {}, right_score: {}, down_score: {}, left_score: {}\n",
+        tree_height, pos, scores[0], scores[1], scores[2], scores[3]
+    );
+
+    scores.iter().product()
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let grid = parse(input);
+    let score = grid
+        .positions()
+        .filter(|&pos| is_visible(&grid, pos))
+        .count();
+    Some(score)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let grid = parse(input);
+    grid.positions().map(|pos| scenic_score(&grid, pos)).max()
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2022, 8);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 8);
+        assert_eq!(part_one(&input), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 8);
+        assert_eq!(part_two(&input), Some(8));
+    }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 8, size);
+            assert_eq!(parse(&input).height(), input.lines().count());
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use advent_of_code::helpers::{Direction, Point2};
+use std::{collections::HashSet, str::FromStr};
+
+#[derive(Debug)]
+struct Command {
+    direction: Direction,
+    distance: u32,
+}
+
+impl FromStr for Command {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let direction = parts.next().unwrap().parse().expect("Invalid direction");
+        let distance = parts.next().unwrap().parse().unwrap();
+        Ok(Command {
+            direction,
+            distance,
+        })
+    }
+}
+
+type Knot = Point2<i32>;
+
+#[derive(Debug)]
+struct Rope {
+    knots: Vec<Knot>,
+    tail_positions: HashSet<Knot>,
+}
+
+impl Rope {
+    fn new(size: usize) -> Self {
+        let knots = vec![Knot::ORIGIN; size];
+        let mut positions = HashSet::new();
+        positions.insert(Knot::ORIGIN);
+        Rope {
+            knots,
+            tail_positions: positions,
+        }
+    }
+
+    fn handle_command(&mut self, command: Command) {
+        for _ in 0..command.distance {
+            self.move_rope(&command.direction);
+            self.tail_positions.insert(*self.knots.last().unwrap());
+        }
+    }
+
+    fn move_rope(&mut self, direction: &Direction) {
+        self.knots[0] += direction.vec();
+        // every other knot follows the previous one, unless they still touch
+        for i in 1..self.knots.len() {
+            self.knots[i] = self.knots[i].follow(self.knots[i - 1]);
+        }
+    }
+
+    fn print(&self) {
+        for (i, knot) in self.knots.iter().enumerate() {
+            advent_of_code::debug!("Knot {} - {}, {}", i, knot.x, knot.y);
+        }
+        advent_of_code::debug!();
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let mut rope = Rope::new(2);
+    // one command at a time, so a panic while moving the rope points at its line.
+    for line in advent_of_code::panics::tracked_lines(input) {
+        rope.handle_command(Command::from_str(line).unwrap());
+    }
+    Some(rope.tail_positions.len())
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let mut rope = Rope::new(10);
+    for line in advent_of_code::panics::tracked_lines(input) {
+        rope.handle_command(Command::from_str(line).unwrap());
+    }
+    advent_of_code::panics::reset_current_line();
+    rope.print();
+    Some(rope.tail_positions.len())
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2022, 9);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs};
+
+    fn read_file(folder: &str, name: &str) -> String {
+        let cwd = env::current_dir().unwrap();
+
+        let filepath = cwd
+            .join("src")
+            .join("y2022")
+            .join(folder)
+            .join(format!("{}.txt", name));
+
+        let f = fs::read_to_string(filepath);
+        f.expect("could not open input file")
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = read_file("examples", "09_01");
+        assert_eq!(part_one(&input), Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = read_file("examples", "09_02");
+        assert_eq!(part_two(&input), Some(36));
+    }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 9, size);
+            for line in input.lines() {
+                assert!(line.parse::<Command>().is_ok(), "{:?}", line);
+            }
+        }
+    }
+}
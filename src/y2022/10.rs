@@ -1,3 +1,4 @@
+use advent_of_code::Answer;
 use std::str::FromStr;
 #[derive(Debug, Clone, Copy)]
 struct Pixel(char);
@@ -10,7 +11,7 @@ struct Row {
 impl Row {
     fn new() -> Self {
         Row {
-            pixels: [Pixel(' '); 40],
+            pixels: [Pixel('.'); 40],
         }
     }
 
@@ -31,13 +32,12 @@ impl Screen {
         }
     }
 
-    fn draw(&self) {
-        for row in &self.rows {
-            for pixel in &row.pixels {
-                print!("{}", pixel.0);
-            }
-            println!();
-        }
+    fn render(&self) -> Answer {
+        Answer::grid(
+            self.rows
+                .iter()
+                .map(|row| row.pixels.iter().map(|pixel| pixel.0).collect::<String>()),
+        )
     }
 }
 
@@ -165,7 +165,7 @@ impl Machine {
     }
 
     fn current_y_pos(&self) -> u32 {
-        self.cycle / 40
+        (self.cycle - 1) / 40
     }
     fn current_x_pos(&self) -> u32 {
         (self.cycle - 1) % 40
@@ -199,7 +199,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(value as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<Answer> {
     let mut machine = Machine::new();
     let instructions: Vec<_> = input
         .lines()
@@ -210,13 +210,11 @@ pub fn part_two(input: &str) -> Option<u32> {
         machine.process(*instruction);
     }
 
-    machine.screen.draw();
-
-    Some(1)
+    Some(machine.screen.render())
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
+    let input = &advent_of_code::read_file("inputs", 2022, 10);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -227,13 +225,33 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10);
         assert_eq!(part_one(&input), Some(13140));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), None);
+        let input = advent_of_code::read_file("examples", 2022, 10);
+        assert_eq!(
+            part_two(&input),
+            Some(Answer::grid([
+                "##..##..##..##..##..##..##..##..##..##..",
+                "###...###...###...###...###...###...###.",
+                "####....####....####....####....####....",
+                "#####.....#####.....#####.....#####.....",
+                "######......######......######......####",
+                "#######.......#######.......#######.....",
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 10, size);
+            for line in input.lines() {
+                assert!(line.parse::<Instruction>().is_ok(), "{:?}", line);
+            }
+        }
     }
 }
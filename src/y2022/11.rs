@@ -1,15 +1,21 @@
+use advent_of_code::helpers::math::{add, lcm_of, mul};
+use advent_of_code::helpers::parse::ints;
+use advent_of_code::helpers::Progress;
 use std::{cell::RefCell, str::FromStr};
 
 use itertools::Itertools;
 
+/// part one only divides worry levels by 3, so they need room to grow. inputs that overflow even `u128`
+/// panic with the overflowing operation rather than wrap around.
 #[derive(Debug, Clone, Copy)]
 struct Item {
     worry_level: u128,
 }
 
 impl Item {
-    fn apply(&mut self, operation: &Operation) {
-        self.worry_level = operation.apply(self.worry_level);
+    fn apply(&mut self, operation: &Operation) -> Result<(), String> {
+        self.worry_level = operation.apply(self.worry_level)?;
+        Ok(())
     }
 
     fn div(&mut self, n: u128) {
@@ -21,15 +27,6 @@ impl Item {
     }
 }
 
-impl FromStr for Item {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let worry_level = s.parse().expect(s);
-        Ok(Item { worry_level })
-    }
-}
-
 #[derive(Debug)]
 enum Operation {
     Add(u128),
@@ -41,24 +38,22 @@ impl FromStr for Operation {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.chars().nth(23) == Some('+') {
-            Ok(Operation::Add(s[25..].parse().unwrap()))
-        } else if &s[25..] == "old" {
-            Ok(Operation::Square)
-        } else if s.chars().nth(23) == Some('*') {
-            Ok(Operation::Multiply(s[25..].parse().unwrap()))
-        } else {
-            panic!("Unknown operation: {}", s)
+        let (_, operation) = s.split_once("new = old ").ok_or(())?;
+        match operation.split_once(' ') {
+            Some((_, "old")) => Ok(Operation::Square),
+            Some(("+", n)) => Ok(Operation::Add(n.parse().unwrap())),
+            Some(("*", n)) => Ok(Operation::Multiply(n.parse().unwrap())),
+            _ => panic!("Unknown operation: {}", s),
         }
     }
 }
 
 impl Operation {
-    fn apply(&self, x: u128) -> u128 {
+    fn apply(&self, x: u128) -> Result<u128, String> {
         match self {
-            Operation::Add(n) => x + n,
-            Operation::Multiply(n) => x * n,
-            Operation::Square => x * x,
+            Operation::Add(n) => add(x, *n, "worry level"),
+            Operation::Multiply(n) => mul(x, *n, "worry level"),
+            Operation::Square => mul(x, x, "worry level"),
         }
     }
 }
@@ -79,21 +74,16 @@ impl FromStr for Monkey {
         let mut lines = s.lines();
         let _name = lines.next();
         let items = RefCell::new(
-            lines
-                .next()
-                .unwrap()
-                .split_once(':')
-                .unwrap()
-                .1
-                .split(", ")
-                .map(|c| c.trim().parse().unwrap())
+            ints(lines.next().unwrap())
+                .into_iter()
+                .map(|worry_level| Item { worry_level })
                 .collect(),
         );
 
         let operation = lines.next().unwrap().parse().unwrap();
-        let test_divisor = lines.next().unwrap()[21..].parse().unwrap();
-        let target_true = lines.next().unwrap()[29..].parse().unwrap();
-        let target_false = lines.next().unwrap()[30..].parse().unwrap();
+        let test_divisor = ints(lines.next().unwrap())[0];
+        let target_true = ints(lines.next().unwrap())[0];
+        let target_false = ints(lines.next().unwrap())[0];
         Ok(Monkey {
             items,
             operation,
@@ -106,7 +96,7 @@ impl FromStr for Monkey {
 
 impl Monkey {
     fn test(&self, item: &Item) -> bool {
-        item.worry_level % self.test_divisor == 0
+        item.worry_level.is_multiple_of(self.test_divisor)
     }
 }
 
@@ -118,7 +108,8 @@ pub fn part_one(input: &str) -> Option<u128> {
         for (i, monkey) in monkeys.iter().enumerate() {
             for item in monkey.items.borrow_mut().iter_mut() {
                 inspect_counts[i] += 1;
-                item.apply(&monkey.operation);
+                item.apply(&monkey.operation)
+                    .unwrap_or_else(|e| panic!("{}", e));
                 item.div(3);
                 let target = if monkey.test(item) {
                     monkey.target_true
@@ -145,12 +136,17 @@ pub fn part_two(input: &str) -> Option<u128> {
     let monkeys: Vec<Monkey> = input.split("\n\n").map(|s| s.parse().unwrap()).collect();
     let mut inspect_counts = vec![0; monkeys.len()];
 
-    let lcm: u128 = monkeys.iter().map(|m| m.test_divisor).product();
+    // every test still gives the same result modulo a multiple of its divisor.
+    let divisors: Vec<u128> = monkeys.iter().map(|m| m.test_divisor).collect();
+    let lcm = lcm_of(&divisors).unwrap();
+    let mut progress = Progress::new("rounds").with_total(10000);
     for _ in 1..=10000 {
+        progress.inc();
         for (i, monkey) in monkeys.iter().enumerate() {
             for item in monkey.items.borrow_mut().iter_mut() {
                 inspect_counts[i] += 1;
-                item.apply(&monkey.operation);
+                item.apply(&monkey.operation)
+                    .unwrap_or_else(|e| panic!("{}", e));
                 item.modulo(lcm);
                 let target = if monkey.test(item) {
                     monkey.target_true
@@ -174,7 +170,7 @@ pub fn part_two(input: &str) -> Option<u128> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
+    let input = &advent_of_code::read_file("inputs", 2022, 11);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -185,13 +181,23 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = advent_of_code::read_file("examples", 2022, 11);
         assert_eq!(part_one(&input), Some(10605));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = advent_of_code::read_file("examples", 2022, 11);
         assert_eq!(part_two(&input), Some(2713310158));
     }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 11, size);
+            for block in input.split("\n\n") {
+                assert!(block.parse::<Monkey>().is_ok(), "{:?}", block);
+            }
+        }
+    }
 }
@@ -0,0 +1,116 @@
+use advent_of_code::helpers::{search, Grid, Pos};
+
+#[derive(Debug)]
+struct Height(u32);
+impl TryFrom<char> for Height {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'S' => Ok(Height(1)),
+            'E' => Ok(Height(26)),
+            c => Ok(Height(c as u32 - 'a' as u32 + 1)),
+        }
+    }
+}
+
+impl Height {
+    fn can_reach(&self, other: &Self) -> bool {
+        self.0 + 1 >= other.0
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    start: Pos,
+    end: Pos,
+    heights: Grid<Height>,
+}
+
+impl Map {
+    fn parse(s: &str) -> Self {
+        let chars = Grid::parse(s, Some).unwrap();
+        Map {
+            start: chars.position(|&c| c == 'S').unwrap(),
+            end: chars.position(|&c| c == 'E').unwrap(),
+            heights: chars.map(|&c| Height::try_from(c).unwrap()),
+        }
+    }
+
+    /// the squares reachable in one step from `pos`, at most one higher.
+    fn climbable(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.heights
+            .neighbours4(pos)
+            .filter(move |&neighbour| self.heights[pos].can_reach(&self.heights[neighbour]))
+    }
+
+    /// the squares `pos` can be reached from in one step, at most one lower.
+    fn descendable(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.heights
+            .neighbours4(pos)
+            .filter(move |&neighbour| self.heights[neighbour].can_reach(&self.heights[pos]))
+    }
+
+    fn all_starting_nodes(&self) -> Vec<Pos> {
+        self.heights
+            .iter()
+            .filter(|(_, height)| height.0 == 1)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let map = Map::parse(input);
+    let path = search::bfs_to(map.start, |&pos| map.climbable(pos), |&pos| pos == map.end)?;
+    Some(path.cost as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let map = Map::parse(input);
+    let paths = search::bfs_multi(map.all_starting_nodes(), |&pos| map.climbable(pos));
+    paths.cost(&map.end).map(|cost| cost as u32)
+}
+
+/// walks down from the end and stops at the first square of height `a`.
+fn part_two_from_end(input: &str) -> Option<u32> {
+    let map = Map::parse(input);
+    let path = search::bfs_to(
+        map.end,
+        |&pos| map.descendable(pos),
+        |&pos| map.heights[pos].0 == 1,
+    )?;
+    Some(path.cost as u32)
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2022, 12);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input, "from-end" => part_two_from_end);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 12);
+        assert_eq!(part_one(&input), Some(31));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 12);
+        assert_eq!(part_two(&input), Some(29));
+    }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 12, size);
+            let map = Map::parse(&input);
+            assert_ne!(map.start, map.end);
+        }
+    }
+}
@@ -125,7 +125,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
+    let input = &advent_of_code::read_file("inputs", 2022, 13);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -136,13 +136,27 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = advent_of_code::read_file("examples", 2022, 13);
         assert_eq!(part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = advent_of_code::read_file("examples", 2022, 13);
         assert_eq!(part_two(&input), Some(140));
     }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 13, size);
+            let (rest, pairs) = pairs(&input).unwrap();
+            assert!(
+                rest.trim().is_empty(),
+                "unparsed: {:?}",
+                rest.trim().lines().next()
+            );
+            assert!(!pairs.is_empty());
+        }
+    }
 }
@@ -154,7 +154,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 14);
+    let input = &advent_of_code::read_file("inputs", 2022, 14);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -165,13 +165,27 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 14);
+        let input = advent_of_code::read_file("examples", 2022, 14);
         assert_eq!(part_one(&input), Some(24));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 14);
+        let input = advent_of_code::read_file("examples", 2022, 14);
         assert_eq!(part_two(&input), Some(93));
     }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 14, size);
+            let (rest, rocks) = parse_rocks(&input).unwrap();
+            assert!(
+                rest.trim().is_empty(),
+                "unparsed: {:?}",
+                rest.trim().lines().next()
+            );
+            assert!(!rocks.is_empty());
+        }
+    }
 }
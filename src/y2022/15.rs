@@ -1,3 +1,4 @@
+use advent_of_code::helpers::{Interval, IntervalSet, Progress};
 use std::collections::{BTreeMap, BTreeSet};
 
 use nom::{
@@ -92,7 +93,7 @@ impl Grid {
     }
 
     fn find_intersections_at_y(&self, line: i32) -> i32 {
-        let intersections: BTreeSet<_> = self
+        let covered: IntervalSet<i32> = self
             .sensors
             .iter()
             .filter_map(|s| {
@@ -103,18 +104,16 @@ impl Grid {
                     return None;
                 }
                 let dx = distance_to_beacon - distance_to_line;
-                let points: Vec<_> = ((s.x - dx)..=(s.x + dx))
-                    .into_iter()
-                    .map(|x| Point { x, y: line })
-                    .collect();
-
-                Some(points)
+                Some(Interval::new(s.x - dx, s.x + dx))
             })
-            .flatten()
             .collect();
 
-        intersections.iter().len() as i32
-            - self.beacons.iter().filter(|b| b.y == line).count() as i32
+        covered.len()
+            - self
+                .beacons
+                .iter()
+                .filter(|b| b.y == line && covered.contains(b.x))
+                .count() as i32
     }
 
     fn greated_y_intercept(&self, sensor: &Point, y: i32) -> Option<Point> {
@@ -132,7 +131,9 @@ impl Grid {
     }
 
     fn find_distress_beacon_xy(&self, grid_size: u32) -> Point {
+        let mut progress = Progress::new("rows").with_total(grid_size as u64);
         for y in 0..grid_size {
+            progress.set(y as u64);
             let mut x: u32 = 0;
             'x: while x <= grid_size {
                 for s in &self.sensors {
@@ -144,7 +145,15 @@ impl Grid {
                         y: y as i32,
                     });
 
-                    dbg!(&s, &beacon, distance_to_beacon, distance_to_xy);
+                    advent_of_code::trace!(
+                        "{:?} {:?} distance to beacon: {}, distance to {}, {}: {}",
+                        s,
+                        beacon,
+                        distance_to_beacon,
+                        x,
+                        y,
+                        distance_to_xy
+                    );
 
                     if distance_to_xy < distance_to_beacon {
                         let new_point = self.greated_y_intercept(s, y as i32).unwrap();
@@ -152,7 +161,7 @@ impl Grid {
                         continue 'x;
                     }
                 }
-                println!("No sensor found for {}, {}", x, y);
+                advent_of_code::debug!("No sensor found for {}, {}", x, y);
                 return Point {
                     x: x as i32,
                     y: y as i32,
@@ -179,12 +188,12 @@ pub fn part_two(input: &str) -> Option<u32> {
     let beacon_point = grid.find_distress_beacon_xy(20);
     let f = tuning_frequency(&beacon_point);
 
-    dbg!(&beacon_point, f);
+    advent_of_code::debug!("{:?} {}", beacon_point, f);
     None
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 15);
+    let input = &advent_of_code::read_file("inputs", 2022, 15);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -195,7 +204,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 15);
+        let input = advent_of_code::read_file("examples", 2022, 15);
         let grid = Grid::new(parse(&input).unwrap().1);
         let count = grid.find_intersections_at_y(10);
         assert_eq!(count, 26);
@@ -203,7 +212,21 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 15);
+        let input = advent_of_code::read_file("examples", 2022, 15);
         assert_eq!(part_two(&input), Some(56000011));
     }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 15, size);
+            let (rest, pairs) = parse(&input).unwrap();
+            assert!(
+                rest.trim().is_empty(),
+                "unparsed: {:?}",
+                rest.trim().lines().next()
+            );
+            assert_eq!(pairs.len(), input.lines().count());
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use advent_of_code::helpers::Progress;
 use std::collections::BTreeMap;
 
 use nom::{
@@ -139,10 +140,12 @@ pub fn part_one(input: &str) -> Option<u32> {
     let mut shapes = binding.1.iter().cycle();
 
     let mut rocks_dropped = 0;
+    let mut progress = Progress::new("rocks").with_total(2022);
 
     while rocks_dropped < 2022 {
         let brick: Brick = shapes.next().unwrap().try_into().unwrap();
         rocks_dropped += 1;
+        progress.set(rocks_dropped);
         field.drop_rock();
     }
 
@@ -154,7 +157,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 17);
+    let input = &advent_of_code::read_file("inputs", 2022, 17);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -165,13 +168,27 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 17);
+        let input = advent_of_code::read_file("examples", 2022, 17);
         assert_eq!(part_one(&input), Some(3068));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 17);
+        let input = advent_of_code::read_file("examples", 2022, 17);
         assert_eq!(part_two(&input), None);
     }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 17, size);
+            let (rest, moves) = parse_moves(&input).unwrap();
+            assert!(
+                rest.trim().is_empty(),
+                "unparsed: {:?}",
+                rest.trim().lines().next()
+            );
+            assert!(!moves.is_empty());
+        }
+    }
 }
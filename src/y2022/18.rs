@@ -0,0 +1,42 @@
+use advent_of_code::helpers::VoxelGrid;
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let droplet = VoxelGrid::parse(input).unwrap_or_else(|e| panic!("{}", e));
+    Some(droplet.surface_faces(|&lava| lava))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let droplet = VoxelGrid::parse(input).unwrap_or_else(|e| panic!("{}", e));
+    Some(droplet.exterior_faces(|&lava| lava))
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2022, 18);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 18);
+        assert_eq!(part_one(&input), Some(64));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 18);
+        assert_eq!(part_two(&input), Some(58));
+    }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 18, size);
+            assert!(VoxelGrid::parse(&input).is_ok());
+        }
+    }
+}
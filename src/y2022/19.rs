@@ -64,7 +64,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 19);
+    let input = &advent_of_code::read_file("inputs", 2022, 19);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -75,13 +75,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 19);
+        let input = advent_of_code::read_file("examples", 2022, 19);
         assert_eq!(part_one(&input), None);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 19);
+        let input = advent_of_code::read_file("examples", 2022, 19);
         assert_eq!(part_two(&input), None);
     }
 }
@@ -41,12 +41,12 @@ pub fn part_one(input: &str) -> Option<i64> {
     }
 
     let zero_pos = file.mixed.iter().position(|&x| x == 0).unwrap();
-    dbg!(zero_pos);
+    advent_of_code::debug!("zero_pos: {}", zero_pos);
     let a = file.mixed[(1000 + zero_pos) % file.mixed.len()];
     let b = file.mixed[(2000 + zero_pos) % file.mixed.len()];
     let c = file.mixed[(3000 + zero_pos) % file.mixed.len()];
 
-    dbg!(a, b, c);
+    advent_of_code::debug!("a: {}, b: {}, c: {}", a, b, c);
 
     Some(a + b + c);
     None
@@ -57,7 +57,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 20);
+    let input = &advent_of_code::read_file("inputs", 2022, 20);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -68,13 +68,21 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 20);
+        let input = advent_of_code::read_file("examples", 2022, 20);
         assert_eq!(part_one(&input), Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 20);
+        let input = advent_of_code::read_file("examples", 2022, 20);
         assert_eq!(part_two(&input), None);
     }
+
+    #[test]
+    fn test_generated_input() {
+        for size in [5, 600] {
+            let input = advent_of_code::generators::generated_input(2022, 20, size);
+            assert!(input.parse::<File>().is_ok());
+        }
+    }
 }